@@ -0,0 +1,139 @@
+use std::{fs, io, path::Path};
+
+use crate::{
+    error::{lines, ParseError},
+    solution::Part,
+};
+
+/// Known-good answers of a day, keyed by input file name. They are stored in
+/// a small subset of TOML, one table per input:
+///
+/// ```toml
+/// ["input.txt"]
+/// part1 = 55002
+/// part2 = "55093"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    inputs: Vec<(String, [Option<String>; 2])>,
+}
+
+fn unquote(value: &str) -> Option<&str> {
+    value.strip_prefix('"')?.strip_suffix('"')
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut answers = Answers::default();
+
+        for line in lines(text) {
+            let content = line.text.trim();
+
+            if content.is_empty() || content.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = content.strip_prefix('[') {
+                let name = line.strip_suffix(header, "]")?;
+                let name = unquote(name).unwrap_or(name);
+
+                answers.inputs.push((name.to_string(), [None, None]));
+                continue;
+            }
+
+            let (key, value) = line.split_once(content, "=")?;
+            let (key, value) = (key.trim(), value.trim());
+
+            let part = match key {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                _ => return Err(line.error(key, "'part1' or 'part2'")),
+            };
+
+            let value = match unquote(value) {
+                Some(value) => value,
+                None if !value.is_empty()
+                    && value.chars().all(|c| c.is_ascii_digit() || c == '-') =>
+                {
+                    value
+                }
+                None => return Err(line.error(value, "a number or a quoted string")),
+            };
+
+            let (_, parts) = answers
+                .inputs
+                .last_mut()
+                .ok_or_else(|| line.error(line.text, "an '[\"<input file>\"]' header first"))?;
+
+            parts[part.number() as usize - 1] = Some(value.to_string());
+        }
+
+        Ok(answers)
+    }
+
+    /// Loads the answers at `path`, or no answers at all if there is no file.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Option<Result<Self, ParseError>>> {
+        match fs::read_to_string(path) {
+            Ok(text) => Ok(Some(Self::parse(&text))),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// The registered input file names, in file order.
+    pub fn inputs(&self) -> impl Iterator<Item = &str> {
+        self.inputs.iter().map(|(name, _)| name.as_str())
+    }
+
+    pub fn get(&self, input: &str, part: Part) -> Option<&str> {
+        self.inputs
+            .iter()
+            .find(|(name, _)| name == input)
+            .and_then(|(_, parts)| parts[part.number() as usize - 1].as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "# Known answers
+[\"input.txt\"]
+part1 = 55002
+part2 = \"55093\"
+
+[input2.txt]
+part2 = -3
+",
+        )
+        .unwrap();
+
+        assert_eq!(
+            answers.inputs().collect::<Vec<&str>>(),
+            vec!["input.txt", "input2.txt"]
+        );
+        assert_eq!(answers.get("input.txt", Part::One), Some("55002"));
+        assert_eq!(answers.get("input.txt", Part::Two), Some("55093"));
+        assert_eq!(answers.get("input2.txt", Part::One), None);
+        assert_eq!(answers.get("input2.txt", Part::Two), Some("-3"));
+        assert_eq!(answers.get("input3.txt", Part::One), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Answers::parse("part1 = 1").unwrap_err();
+        assert_eq!(error.line, 1);
+
+        let error = Answers::parse("[input.txt]\npart3 = 1").unwrap_err();
+        assert_eq!((error.line, error.span), (2, 0..5));
+
+        let error = Answers::parse("[input.txt]\npart1 = abc").unwrap_err();
+        assert_eq!(error.expected, "a number or a quoted string");
+
+        let error = Answers::parse("[input.txt\npart1 = 1").unwrap_err();
+        assert_eq!(error.expected, "']'");
+    }
+}
@@ -0,0 +1,91 @@
+use std::path::PathBuf;
+
+use crate::log::parse_verbosity_flag;
+
+pub const USAGE: &str =
+    "Usage: dayNN [<INPUT>|-] [--json] [-v|-vv] [--timings] [--timings-json <PATH>]";
+
+/// Command line options understood by every day binary.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct DayArgs {
+    /// Input file, or `-` for stdin.
+    pub input: Option<PathBuf>,
+    /// Print the wall time of the parse and of each part after the answers.
+    pub timings: bool,
+    /// Also write the timings as JSON to this file.
+    pub timings_json: Option<PathBuf>,
+    /// Print one JSON object per part instead of text.
+    pub json: bool,
+    /// How much diagnostic output the solver writes to stderr.
+    pub verbosity: u8,
+}
+
+pub fn parse_day_args(args: impl Iterator<Item = String>) -> Result<DayArgs, String> {
+    let mut day_args = DayArgs::default();
+
+    let mut args = args;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => day_args.json = true,
+            "--timings" => day_args.timings = true,
+            "--timings-json" => {
+                day_args.timings_json = Some(PathBuf::from(
+                    args.next()
+                        .ok_or("--timings-json expects a path".to_string())?,
+                ))
+            }
+            path if path == "-" || !path.starts_with('-') => {
+                if day_args.input.is_some() {
+                    return Err(format!("Unexpected extra input '{}'", path));
+                }
+
+                day_args.input = Some(PathBuf::from(path));
+            }
+            flag => match parse_verbosity_flag(flag) {
+                Some(level) => day_args.verbosity = level,
+                None => return Err(format!("Unknown argument '{}'", arg)),
+            },
+        }
+    }
+
+    Ok(day_args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<DayArgs, String> {
+        parse_day_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse_day_args() {
+        assert_eq!(parse(&[]), Ok(DayArgs::default()));
+        assert_eq!(
+            parse(&["--timings-json", "t.json", "--timings"]),
+            Ok(DayArgs {
+                timings: true,
+                timings_json: Some(PathBuf::from("t.json")),
+                ..DayArgs::default()
+            })
+        );
+        assert_eq!(
+            parse(&["--json", "-", "-vv"]),
+            Ok(DayArgs {
+                input: Some(PathBuf::from("-")),
+                json: true,
+                verbosity: 2,
+                ..DayArgs::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_day_args_errors() {
+        assert!(parse(&["--timings-json"]).is_err());
+        assert!(parse(&["--verbose"]).is_err());
+        assert!(parse(&["a.txt", "b.txt"]).is_err());
+    }
+}
@@ -0,0 +1,261 @@
+//! A small benchmark harness for `cargo bench`, timing the parse and each
+//! part of a day separately on every committed input of its crate.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, Instant},
+};
+
+use crate::{input::read_input, json, solution::Solution};
+
+const WARM_UP_RUNS: u32 = 1;
+const MAX_SAMPLES: u32 = 100;
+const TIME_BUDGET: Duration = Duration::from_secs(2);
+
+/// Statistics over the samples of a single phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: u32,
+    pub mean: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "Cannot compute stats without samples");
+
+        Self {
+            samples: samples.len() as u32,
+            mean: samples.iter().sum::<Duration>() / samples.len() as u32,
+            min: *samples.iter().min().unwrap(),
+            max: *samples.iter().max().unwrap(),
+        }
+    }
+}
+
+/// Runs `f` a few times to warm up, then samples it until either the time
+/// budget is spent or enough samples were taken. Always takes one sample.
+pub fn measure(mut f: impl FnMut()) -> Stats {
+    for _ in 0..WARM_UP_RUNS {
+        f();
+    }
+
+    let mut samples = Vec::new();
+    let budget_start = Instant::now();
+
+    while samples.is_empty()
+        || (budget_start.elapsed() < TIME_BUDGET && (samples.len() as u32) < MAX_SAMPLES)
+    {
+        let start = Instant::now();
+        f();
+        samples.push(start.elapsed());
+    }
+
+    Stats::from_samples(&samples)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    pub day: u8,
+    pub input: String,
+    pub phase: &'static str,
+    pub stats: Stats,
+}
+
+impl Measurement {
+    pub fn to_json(&self) -> String {
+        json::object(&[
+            ("day", self.day.to_string()),
+            ("input", json::string(&self.input)),
+            ("phase", json::string(self.phase)),
+            ("samples", self.stats.samples.to_string()),
+            ("mean_ns", self.stats.mean.as_nanos().to_string()),
+            ("min_ns", self.stats.min.as_nanos().to_string()),
+            ("max_ns", self.stats.max.as_nanos().to_string()),
+        ])
+    }
+}
+
+/// Benchmarks the requested phases (`parse`, `part1`, `part2`) of `S` on
+/// `input`, labelling the results with `name`.
+pub fn bench_input<S: Solution>(
+    name: &str,
+    input: &str,
+    phases: &[&'static str],
+) -> Vec<Measurement> {
+    let parsed = match S::parse(input) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!("{}\n{}", name, error);
+            return Vec::new();
+        }
+    };
+
+    phases
+        .iter()
+        .map(|phase| {
+            let stats = match *phase {
+                "parse" => measure(|| {
+                    S::parse(input).ok();
+                }),
+                "part1" => measure(|| {
+                    S::part1(&parsed).to_string();
+                }),
+                "part2" => measure(|| {
+                    S::part2(&parsed).to_string();
+                }),
+                _ => unreachable!("Unknown phase {}", phase),
+            };
+
+            Measurement {
+                day: S::DAY,
+                input: name.to_string(),
+                phase,
+                stats,
+            }
+        })
+        .collect()
+}
+
+/// The `input*.txt` files committed in `dir`, sorted by name.
+pub fn committed_inputs(dir: &Path) -> Vec<PathBuf> {
+    let mut inputs: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| {
+                    path.file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| name.starts_with("input") && name.ends_with(".txt"))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    inputs.sort();
+
+    inputs
+}
+
+const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// Entry point of a day's `benches/bench.rs`. Accepts phase names to only
+/// run those, and `--json <path>` to export the results.
+pub fn main<S: Solution>(crate_dir: &str) {
+    let mut phases: Vec<&'static str> = Vec::new();
+    let mut json_path = None;
+
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            // Passed by `cargo bench` to every bench target.
+            "--bench" => {}
+            "--json" => json_path = args.next().map(PathBuf::from),
+            phase => match PHASES.iter().find(|p| **p == phase) {
+                Some(phase) => phases.push(phase),
+                None => {
+                    eprintln!("Unknown phase '{}', expected one of {:?}", phase, PHASES);
+                    process::exit(2);
+                }
+            },
+        }
+    }
+
+    if phases.is_empty() {
+        phases = PHASES.to_vec();
+    }
+
+    let mut measurements = Vec::new();
+
+    for path in committed_inputs(Path::new(crate_dir)) {
+        let name = path.file_name().unwrap().to_string_lossy().to_string();
+        let input = read_input(&path).unwrap();
+
+        for measurement in bench_input::<S>(&name, &input, &phases) {
+            println!(
+                "day{:02}/{:<12} {:<6} mean {:>10.2?}  min {:>10.2?}  max {:>10.2?}  ({} samples)",
+                measurement.day,
+                measurement.input,
+                measurement.phase,
+                measurement.stats.mean,
+                measurement.stats.min,
+                measurement.stats.max,
+                measurement.stats.samples
+            );
+
+            measurements.push(measurement);
+        }
+    }
+
+    if let Some(path) = json_path {
+        let values: Vec<String> = measurements.iter().map(|m| m.to_json()).collect();
+
+        fs::write(&path, json::array(&values) + "\n").unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::tests::Sum;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let stats = Stats::from_samples(&[
+            Duration::from_millis(1),
+            Duration::from_millis(3),
+            Duration::from_millis(2),
+        ]);
+
+        assert_eq!(stats.samples, 3);
+        assert_eq!(stats.mean, Duration::from_millis(2));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.max, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn test_measure_takes_samples() {
+        let mut runs = 0;
+
+        let stats = measure(|| runs += 1);
+
+        assert!(stats.samples >= 1);
+        assert_eq!(runs, stats.samples + WARM_UP_RUNS);
+    }
+
+    #[test]
+    fn test_bench_input() {
+        let measurements = bench_input::<Sum>("input.txt", "1 2 3", &["parse", "part2"]);
+
+        let phases: Vec<&str> = measurements.iter().map(|m| m.phase).collect();
+        assert_eq!(phases, vec!["parse", "part2"]);
+        assert!(measurements[0]
+            .to_json()
+            .starts_with("{\"day\":0,\"input\":\"input.txt\",\"phase\":\"parse\",\"samples\":"));
+    }
+
+    #[test]
+    fn test_bench_input_parse_error() {
+        assert!(bench_input::<Sum>("input.txt", "1 x", &["parse"]).is_empty());
+    }
+
+    #[test]
+    fn test_committed_inputs() {
+        let dir = env::temp_dir().join(format!("aoc-core-bench-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        for name in ["input2.txt", "input.txt", "answers.toml", "notes.txt"] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+
+        let inputs = committed_inputs(&dir);
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(inputs, vec![dir.join("input.txt"), dir.join("input2.txt")]);
+    }
+}
@@ -0,0 +1,156 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::fetch::{FetchError, Fetcher};
+
+/// Overrides the cache location, `$XDG_CACHE_HOME/aoc` or `~/.cache/aoc` by
+/// default.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+/// Puzzle inputs stored on disk as `<dir>/<year>/dayNN/input.txt`, so that a
+/// year directory can be used as `AOC_INPUT_DIR`. An input is only ever
+/// fetched once: whatever is cached is served as is.
+#[derive(Debug)]
+pub struct InputCache<F> {
+    dir: PathBuf,
+    fetcher: F,
+}
+
+impl<F: Fetcher> InputCache<F> {
+    pub fn new(dir: impl Into<PathBuf>, fetcher: F) -> Self {
+        Self {
+            dir: dir.into(),
+            fetcher,
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{:02}", day))
+            .join("input.txt")
+    }
+
+    pub fn is_cached(&self, year: u16, day: u8) -> bool {
+        self.path(year, day).is_file()
+    }
+
+    /// Returns the path of the cached input, fetching it first if needed.
+    pub fn get(&self, year: u16, day: u8) -> Result<PathBuf, FetchError> {
+        let path = self.path(year, day);
+
+        if path.is_file() {
+            return Ok(path);
+        }
+
+        let input = self.fetcher.fetch(year, day)?;
+
+        if input.trim().is_empty() {
+            return Err(FetchError::Http(format!(
+                "empty input for {} day {}",
+                year, day
+            )));
+        }
+
+        fs::create_dir_all(path.parent().unwrap())?;
+
+        // Write then rename, so that an interrupted download never leaves a
+        // truncated input behind that would be served from then on.
+        let partial = path.with_extension("txt.partial");
+        fs::write(&partial, input)?;
+        fs::rename(&partial, &path)?;
+
+        Ok(path)
+    }
+
+    pub fn read(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        Ok(fs::read_to_string(self.get(year, day)?)?)
+    }
+}
+
+/// The cache location from `AOC_CACHE_DIR`, `XDG_CACHE_HOME` or `HOME`.
+pub fn default_cache_dir() -> io::Result<PathBuf> {
+    let var = |name| env::var_os(name).filter(|value| !value.is_empty());
+
+    if let Some(dir) = var(CACHE_DIR_VAR) {
+        Ok(PathBuf::from(dir))
+    } else if let Some(dir) = var("XDG_CACHE_HOME") {
+        Ok(Path::new(&dir).join("aoc"))
+    } else if let Some(home) = var("HOME") {
+        Ok(Path::new(&home).join(".cache/aoc"))
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("no cache directory, set {}", CACHE_DIR_VAR),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::DirFetcher;
+    use std::{cell::Cell, process};
+
+    /// Counts the downloads, and serves the day number as the input.
+    struct CountingFetcher {
+        fetches: Cell<u32>,
+    }
+
+    impl Fetcher for CountingFetcher {
+        fn fetch(&self, _year: u16, day: u8) -> Result<String, FetchError> {
+            self.fetches.set(self.fetches.get() + 1);
+            Ok(format!("{}\n", day))
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-core-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_never_fetches_twice() {
+        let dir = temp_dir("cache");
+        let cache = InputCache::new(
+            &dir,
+            CountingFetcher {
+                fetches: Cell::new(0),
+            },
+        );
+
+        assert!(!cache.is_cached(2023, 5));
+        assert_eq!(cache.read(2023, 5).unwrap(), "5\n");
+        assert_eq!(cache.read(2023, 5).unwrap(), "5\n");
+        assert!(cache.is_cached(2023, 5));
+        assert_eq!(cache.fetcher.fetches.get(), 1);
+        assert_eq!(cache.path(2023, 5), dir.join("2023/day05/input.txt"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_serves_cached_input_as_is() {
+        let dir = temp_dir("cache-existing");
+        let cache = InputCache::new(&dir, DirFetcher::new(dir.join("nowhere")));
+
+        fs::create_dir_all(dir.join("2023/day07")).unwrap();
+        fs::write(cache.path(2023, 7), "32T3K 765\n").unwrap();
+
+        assert_eq!(cache.read(2023, 7).unwrap(), "32T3K 765\n");
+        assert!(matches!(
+            cache.get(2023, 8),
+            Err(FetchError::NotFound { year: 2023, day: 8 })
+        ));
+        assert!(!cache.is_cached(2023, 8));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,231 @@
+use std::{error::Error, fmt, ops::Range, str::FromStr};
+
+/// A malformed piece of puzzle input, located by line and column so that it
+/// can be rendered with a caret under the offending token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// Byte range of the offending token within `text`.
+    pub span: Range<usize>,
+    pub expected: String,
+    pub text: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, text: &str, span: Range<usize>, expected: impl Into<String>) -> Self {
+        Self {
+            line,
+            span,
+            expected: expected.into(),
+            text: text.to_string(),
+        }
+    }
+
+    /// An error for input that stops before `line`.
+    pub fn end_of_input(line: usize, expected: impl Into<String>) -> Self {
+        Self::new(line, "", 0..0, expected)
+    }
+
+    /// 1-based column of the first character of the span.
+    pub fn column(&self) -> usize {
+        self.text[..self.span.start].chars().count() + 1
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let padding = " ".repeat(self.column() - 1);
+        let carets = "^".repeat(self.text[self.span.clone()].chars().count().max(1));
+
+        writeln!(f, "error: expected {}", self.expected)?;
+        writeln!(
+            f,
+            "{}--> line {}, column {}",
+            gutter,
+            self.line,
+            self.column()
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(f, "{} | {}{}", gutter, padding, carets)
+    }
+}
+
+impl Error for ParseError {}
+
+/// A line of puzzle input along with its 1-based line number. Tokens handed
+/// to its methods must be slices of `text`, so errors can point at them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Self { number, text }
+    }
+
+    fn span_of(&self, token: &str) -> Range<usize> {
+        let start = (token.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|start| start + token.len() <= self.text.len())
+            .unwrap_or_else(|| panic!("'{}' is not a slice of line {}", token, self.number));
+
+        start..start + token.len()
+    }
+
+    /// An error pointing at `token`.
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.text, self.span_of(token), expected)
+    }
+
+    /// An error pointing just past the end of `token`, for something missing.
+    pub fn error_after(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        let end = self.span_of(token).end;
+
+        ParseError::new(self.number, self.text, end..end, expected)
+    }
+
+    pub fn split_once(
+        &self,
+        token: &'a str,
+        separator: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        // Surrounding spaces are noise in a diagnostic, unless that is all
+        // there is to the separator.
+        let shown = match separator.trim() {
+            "" => separator,
+            trimmed => trimmed,
+        };
+
+        token
+            .split_once(separator)
+            .ok_or_else(|| self.error_after(token, format!("'{}'", shown)))
+    }
+
+    pub fn strip_prefix(&self, token: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        token
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error(token, format!("'{}'", prefix)))
+    }
+
+    pub fn strip_suffix(&self, token: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+        token
+            .strip_suffix(suffix)
+            .ok_or_else(|| self.error_after(token, format!("'{}'", suffix)))
+    }
+
+    pub fn number<T: FromStr>(&self, token: &'a str) -> Result<T, ParseError> {
+        token
+            .parse::<T>()
+            .map_err(|_| self.error(token, "a number"))
+    }
+
+    /// Parses every whitespace-separated token of `token` as a number.
+    pub fn numbers<T: FromStr>(&self, token: &'a str) -> Result<Vec<T>, ParseError> {
+        token.split_whitespace().map(|n| self.number(n)).collect()
+    }
+
+    /// Parses every `separator`-separated token of `token` as a number.
+    pub fn numbers_sep<T: FromStr>(
+        &self,
+        token: &'a str,
+        separator: &str,
+    ) -> Result<Vec<T>, ParseError> {
+        token
+            .split(separator)
+            .map(|n| self.number(n.trim()))
+            .collect()
+    }
+}
+
+/// The lines of `input`, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, text)| Line::new(i + 1, text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let line = Line::new(3, "Card 1: 41 x8 | 83");
+        let error = line.number::<u32>(&line.text[11..13]).unwrap_err();
+
+        assert_eq!(error.column(), 12);
+        assert_eq!(
+            error.to_string(),
+            "error: expected a number
+ --> line 3, column 12
+  |
+3 | Card 1: 41 x8 | 83
+  |            ^^"
+        );
+    }
+
+    #[test]
+    fn test_render_missing() {
+        let line = Line::new(12, "AAA = (BBB, CCC");
+        let error = line.strip_suffix(line.text, ")").unwrap_err();
+
+        assert_eq!(error.span, 15..15);
+        assert_eq!(
+            error.to_string(),
+            "error: expected ')'
+  --> line 12, column 16
+   |
+12 | AAA = (BBB, CCC
+   |                ^"
+        );
+    }
+
+    #[test]
+    fn test_column_counts_chars() {
+        let line = Line::new(1, "é x");
+        let error = line.number::<u32>(&line.text[3..]).unwrap_err();
+
+        assert_eq!(error.column(), 3);
+    }
+
+    #[test]
+    fn test_split_once() {
+        let line = Line::new(1, "Game 1: 3 blue");
+
+        assert_eq!(line.split_once(line.text, ": "), Ok(("Game 1", "3 blue")));
+
+        let error = line.split_once(line.text, " | ").unwrap_err();
+        assert_eq!(error.expected, "'|'");
+        assert_eq!(error.span, 14..14);
+    }
+
+    #[test]
+    fn test_numbers() {
+        let line = Line::new(1, "seeds: 79 14 55");
+        let (_, numbers) = line.split_once(line.text, ":").unwrap();
+
+        assert_eq!(line.numbers::<u64>(numbers), Ok(vec![79, 14, 55]));
+    }
+
+    #[test]
+    fn test_numbers_sep() {
+        let line = Line::new(1, "???.### 1,x,3");
+        let (_, hints) = line.split_once(line.text, " ").unwrap();
+
+        let error = line.numbers_sep::<u32>(hints, ",").unwrap_err();
+        assert_eq!(error.span, 10..11);
+    }
+
+    #[test]
+    fn test_lines() {
+        let numbers: Vec<usize> = lines("a\nb\nc").map(|line| line.number).collect();
+
+        assert_eq!(numbers, vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,249 @@
+//! Puzzle examples stored once as fixtures and checked for both parts. Each
+//! day keeps them in `examples/*.txt`, the expected answers first:
+//!
+//! ```text
+//! part1: 142
+//! part2: 281
+//! ---
+//! 1abc2
+//! pqr3stu8vwx
+//! ```
+//!
+//! A part without an answer is not checked against that example, since the
+//! puzzles often give a different example for each part.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    error::{lines, ParseError},
+    solution::{solve, Part, Solution},
+};
+
+const SEPARATOR: &str = "---";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub answers: [Option<String>; 2],
+    pub input: String,
+}
+
+impl Example {
+    pub fn parse(name: &str, text: &str) -> Result<Self, ParseError> {
+        let mut answers = [None, None];
+        let mut input_start = None;
+
+        for line in lines(text) {
+            let content = line.text.trim();
+
+            if content == SEPARATOR {
+                input_start = Some(line.number);
+                break;
+            }
+
+            if content.is_empty() || content.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line.split_once(content, ":")?;
+            let (key, value) = (key.trim(), value.trim());
+
+            let part = match key {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                _ => return Err(line.error(key, "'part1' or 'part2'")),
+            };
+
+            if value.is_empty() {
+                return Err(line.error_after(content, "an answer"));
+            }
+
+            answers[part.number() as usize - 1] = Some(value.to_string());
+        }
+
+        let input_start = input_start.ok_or_else(|| {
+            ParseError::end_of_input(text.lines().count(), "a '---' line before the input")
+        })?;
+
+        Ok(Self {
+            name: name.to_string(),
+            answers,
+            input: text
+                .split_inclusive('\n')
+                .skip(input_start)
+                .collect::<String>(),
+        })
+    }
+
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        self.answers[part.number() as usize - 1].as_deref()
+    }
+}
+
+/// The `*.txt` files of `dir`, sorted by name.
+fn fixture_paths(dir: &Path) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    paths.sort();
+
+    paths
+}
+
+/// Loads every example in `<crate_dir>/examples`.
+pub fn load(crate_dir: &Path) -> Result<Vec<Example>, String> {
+    fixture_paths(&crate_dir.join("examples"))
+        .iter()
+        .map(|path| {
+            let name = path.file_name().unwrap().to_string_lossy().to_string();
+            let text = fs::read_to_string(path).map_err(|error| format!("{}: {}", name, error))?;
+
+            Example::parse(&name, &text).map_err(|error| format!("{}\n{}", name, error))
+        })
+        .collect()
+}
+
+/// The input of the example fixture `name` of the crate at `crate_dir`, for
+/// unit tests of the steps of a solver.
+pub fn input(crate_dir: &str, name: &str) -> String {
+    let path = Path::new(crate_dir).join("examples").join(name);
+    let text =
+        fs::read_to_string(&path).unwrap_or_else(|error| panic!("{}: {}", path.display(), error));
+
+    Example::parse(name, &text)
+        .unwrap_or_else(|error| panic!("{}\n{}", name, error))
+        .input
+}
+
+/// Solves `part` of every example that has an answer for it, returning one
+/// message per example that does not match.
+pub fn check<S: Solution>(examples: &[Example], part: Part) -> Vec<String> {
+    examples
+        .iter()
+        .filter_map(|example| {
+            let expected = example.answer(part)?;
+
+            match solve::<S>(&example.input, part) {
+                Ok(actual) if actual == expected => None,
+                Ok(actual) => Some(format!(
+                    "{} part {}: expected {}, got {}",
+                    example.name,
+                    part.number(),
+                    expected,
+                    actual
+                )),
+                Err(error) => Some(format!(
+                    "{} part {}:\n{}",
+                    example.name,
+                    part.number(),
+                    error
+                )),
+            }
+        })
+        .collect()
+}
+
+/// Checks `part` of `S` against the examples of the crate at `crate_dir`,
+/// panicking with every mismatch. A day must have at least one example.
+pub fn assert_examples<S: Solution>(crate_dir: &str, part: Part) {
+    let examples = load(Path::new(crate_dir)).unwrap_or_else(|error| panic!("{}", error));
+
+    assert!(
+        !examples.is_empty(),
+        "No examples in {}/examples",
+        crate_dir
+    );
+
+    let failures = check::<S>(&examples, part);
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Generates one test per part checking a day against its example fixtures.
+/// Meant to be called inside the day's test module.
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty) => {
+        #[test]
+        fn test_examples_part1() {
+            $crate::examples::assert_examples::<$solution>(
+                env!("CARGO_MANIFEST_DIR"),
+                $crate::Part::One,
+            );
+        }
+
+        #[test]
+        fn test_examples_part2() {
+            $crate::examples::assert_examples::<$solution>(
+                env!("CARGO_MANIFEST_DIR"),
+                $crate::Part::Two,
+            );
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::tests::Sum;
+
+    #[test]
+    fn test_parse() {
+        let example = Example::parse(
+            "sum.txt",
+            "# From the puzzle
+part2: 24
+
+---
+1 2
+
+3 4
+",
+        )
+        .unwrap();
+
+        assert_eq!(example.answer(Part::One), None);
+        assert_eq!(example.answer(Part::Two), Some("24"));
+        assert_eq!(example.input, "1 2\n\n3 4\n");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Example::parse("a.txt", "part1: 3\n1 2").unwrap_err();
+        assert_eq!(error.line, 2);
+
+        let error = Example::parse("a.txt", "part1: 3").unwrap_err();
+        assert_eq!(error.expected, "a '---' line before the input");
+
+        let error = Example::parse("a.txt", "part3: 3\n---\n").unwrap_err();
+        assert_eq!((error.line, error.span), (1, 0..5));
+
+        let error = Example::parse("a.txt", "part1:\n---\n").unwrap_err();
+        assert_eq!(error.expected, "an answer");
+    }
+
+    #[test]
+    fn test_check() {
+        let examples = vec![
+            Example::parse("a.txt", "part1: 10\npart2: 25\n---\n1 2 3 4").unwrap(),
+            Example::parse("b.txt", "part2: 6\n---\n1 x").unwrap(),
+        ];
+
+        assert!(check::<Sum>(&examples, Part::One).is_empty());
+
+        let failures = check::<Sum>(&examples, Part::Two);
+
+        assert_eq!(failures.len(), 2);
+        assert_eq!(failures[0], "a.txt part 2: expected 25, got 24");
+        assert!(failures[1].starts_with("b.txt part 2:\nerror: expected a number"));
+    }
+}
@@ -0,0 +1,255 @@
+//! Where puzzle inputs come from when they are not cached yet: the Advent of
+//! Code website, or a local directory standing in for it.
+
+use std::{
+    env, fmt, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+pub const YEAR: u16 = 2023;
+
+/// Environment variable holding the session token.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Points to a file holding the session token, `~/.config/aoc/session` by default.
+pub const SESSION_FILE_VAR: &str = "AOC_SESSION_FILE";
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// No session token was configured, so the website cannot be queried.
+    MissingSession,
+    /// The input does not exist, e.g. a day that is not unlocked yet.
+    NotFound {
+        year: u16,
+        day: u8,
+    },
+    Io(io::Error),
+    Http(String),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::MissingSession => write!(
+                f,
+                "no session token, set {} or write it to ~/.config/aoc/session",
+                SESSION_VAR
+            ),
+            FetchError::NotFound { year, day } => {
+                write!(f, "no input for {} day {}", year, day)
+            }
+            FetchError::Io(error) => write!(f, "{}", error),
+            FetchError::Http(message) => write!(f, "download failed: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<io::Error> for FetchError {
+    fn from(error: io::Error) -> Self {
+        FetchError::Io(error)
+    }
+}
+
+/// Provides the input of a day, which is then cached by `InputCache`.
+pub trait Fetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError>;
+}
+
+/// The value of the `session` cookie of a logged-in adventofcode.com user.
+/// It is never printed, not even by `Debug`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Session(String);
+
+impl Session {
+    /// Trims `token`, rejecting anything but a non-empty alphanumeric one
+    /// since it ends up in a request header.
+    pub fn new(token: &str) -> Option<Self> {
+        let token = token.trim();
+
+        if token.is_empty() || !token.chars().all(|c| c.is_ascii_alphanumeric()) {
+            None
+        } else {
+            Some(Session(token.to_string()))
+        }
+    }
+
+    /// Reads the token from `AOC_SESSION`, else from the session file.
+    pub fn from_env() -> Result<Self, FetchError> {
+        if let Some(token) = env::var_os(SESSION_VAR) {
+            return Session::new(&token.to_string_lossy()).ok_or(FetchError::MissingSession);
+        }
+
+        let path = match env::var_os(SESSION_FILE_VAR) {
+            Some(path) => PathBuf::from(path),
+            None => match env::var_os("HOME") {
+                Some(home) => Path::new(&home).join(".config/aoc/session"),
+                None => return Err(FetchError::MissingSession),
+            },
+        };
+
+        match fs::read_to_string(path) {
+            Ok(token) => Session::new(&token).ok_or(FetchError::MissingSession),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                Err(FetchError::MissingSession)
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+}
+
+impl fmt::Debug for Session {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Session(<redacted>)")
+    }
+}
+
+/// Downloads inputs from adventofcode.com with `curl`, so that the workspace
+/// does not need an HTTP client.
+#[derive(Debug, Clone)]
+pub struct HttpFetcher {
+    session: Session,
+    base_url: String,
+}
+
+impl HttpFetcher {
+    pub fn new(session: Session) -> Self {
+        Self {
+            session,
+            base_url: "https://adventofcode.com".to_string(),
+        }
+    }
+
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    /// The curl config for a request, handed over on stdin rather than as
+    /// arguments so that the token does not show up in the process list.
+    fn curl_config(&self, year: u16, day: u8) -> String {
+        format!(
+            "url = \"{}\"\nheader = \"Cookie: session={}\"\nuser-agent = \"aoc-2023 input cache (curl)\"\n",
+            self.url(year, day),
+            self.session.0
+        )
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let mut curl = Command::new("curl")
+            .args(["--silent", "--show-error", "--location", "--config", "-"])
+            .args(["--write-out", "\n%{http_code}"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        curl.stdin
+            .take()
+            .unwrap()
+            .write_all(self.curl_config(year, day).as_bytes())?;
+
+        let output = curl.wait_with_output()?;
+
+        if !output.status.success() {
+            return Err(FetchError::Http(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+
+        let body = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = body.rsplit_once('\n').unwrap_or(("", &body));
+
+        match status {
+            "200" => Ok(body.to_string()),
+            "404" => Err(FetchError::NotFound { year, day }),
+            // The website answers 400 when the session is invalid or expired.
+            "400" => Err(FetchError::Http(
+                "the session token was rejected, it may have expired".to_string(),
+            )),
+            status => Err(FetchError::Http(format!("HTTP status {}", status))),
+        }
+    }
+}
+
+/// Serves inputs from `dir/dayNN/input.txt`, the layout of this workspace,
+/// e.g. to seed a cache offline or to stand in for the website in tests.
+#[derive(Debug, Clone)]
+pub struct DirFetcher {
+    dir: PathBuf,
+}
+
+impl DirFetcher {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+impl Fetcher for DirFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let path = self.dir.join(format!("day{:02}", day)).join("input.txt");
+
+        match fs::read_to_string(path) {
+            Ok(input) => Ok(input),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                Err(FetchError::NotFound { year, day })
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session() {
+        assert_eq!(
+            Session::new("  abc123\n"),
+            Some(Session("abc123".to_string()))
+        );
+        assert_eq!(Session::new(" \n"), None);
+        assert_eq!(Session::new("abc 123"), None);
+        assert_eq!(Session::new("abc\"\nheader = x"), None);
+        assert_eq!(
+            format!("{:?}", Session::new("abc123").unwrap()),
+            "Session(<redacted>)"
+        );
+    }
+
+    #[test]
+    fn test_curl_config() {
+        let fetcher = HttpFetcher::new(Session::new("abc123").unwrap());
+
+        assert_eq!(
+            fetcher.url(2023, 5),
+            "https://adventofcode.com/2023/day/5/input"
+        );
+        assert!(fetcher
+            .curl_config(2023, 5)
+            .contains("header = \"Cookie: session=abc123\"\n"));
+        assert!(!format!("{:?}", fetcher).contains("abc123"));
+    }
+
+    #[test]
+    fn test_dir_fetcher() {
+        let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let fetcher = DirFetcher::new(workspace);
+
+        assert_eq!(
+            fetcher.fetch(YEAR, 1).unwrap(),
+            fs::read_to_string(workspace.join("day01/input.txt")).unwrap()
+        );
+        assert!(matches!(
+            fetcher.fetch(YEAR, 26),
+            Err(FetchError::NotFound {
+                year: 2023,
+                day: 26
+            })
+        ));
+    }
+}
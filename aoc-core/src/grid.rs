@@ -0,0 +1,323 @@
+use std::ops::{Index, IndexMut};
+
+use crate::error::{lines, ParseError};
+
+pub type Cell = (i32, i32); // (row, col)
+
+const NEIGHBOURS: [Cell; 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+const ORTHOGONAL_NEIGHBOURS: [Cell; 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// A rectangular grid stored row by row in a single contiguous buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "Grid of {}x{} needs {} cells",
+            width,
+            height,
+            width * height
+        );
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Builds a grid from the lines of `input`, converting every character
+    /// with `f`. All lines must have the same length.
+    pub fn parse_with(input: &str, f: impl Fn(char) -> T) -> Self {
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in input.lines() {
+            let row_start = cells.len();
+            cells.extend(line.chars().map(&f));
+
+            let row_width = cells.len() - row_start;
+
+            if height == 0 {
+                width = row_width;
+            }
+
+            assert_eq!(
+                row_width, width,
+                "Row {} has {} cells, expected {}",
+                height, row_width, width
+            );
+
+            height += 1;
+        }
+
+        Self::new(width, height, cells)
+    }
+
+    /// Same as `parse_with`, but a character `f` rejects, or a row whose
+    /// length differs from the first one, is a parse error. `expected`
+    /// describes the characters `f` accepts.
+    pub fn try_parse_with(
+        input: &str,
+        f: impl Fn(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in lines(input) {
+            let row_start = cells.len();
+
+            for (i, c) in line.text.char_indices() {
+                let cell =
+                    f(c).ok_or_else(|| line.error(&line.text[i..i + c.len_utf8()], expected))?;
+                cells.push(cell);
+            }
+
+            let row_width = cells.len() - row_start;
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(line.error(line.text, format!("a row of {} cells", width)));
+                }
+                Some(_) => {}
+            }
+
+            height += 1;
+        }
+
+        Ok(Self::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): Cell) -> bool {
+        row >= 0 && col >= 0 && (row as usize) < self.height && (col as usize) < self.width
+    }
+
+    pub fn get(&self, cell: Cell) -> Option<&T> {
+        if !self.contains(cell) {
+            return None;
+        }
+
+        Some(&self.cells[cell.0 as usize * self.width + cell.1 as usize])
+    }
+
+    pub fn get_mut(&mut self, cell: Cell) -> Option<&mut T> {
+        if !self.contains(cell) {
+            return None;
+        }
+
+        Some(&mut self.cells[cell.0 as usize * self.width + cell.1 as usize])
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a zero chunk size, which an empty grid would give.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Iterates over every cell in row-major order along with its position.
+    pub fn iter(&self) -> impl Iterator<Item = (Cell, &T)> {
+        self.cells.iter().enumerate().map(|(i, value)| {
+            let cell = ((i / self.width) as i32, (i % self.width) as i32);
+            (cell, value)
+        })
+    }
+
+    /// The up to eight in-bounds cells surrounding `cell`, diagonals included.
+    pub fn neighbours(&self, cell: Cell) -> impl Iterator<Item = Cell> + '_ {
+        self.offsets(cell, &NEIGHBOURS)
+    }
+
+    /// The up to four in-bounds cells sharing an edge with `cell`.
+    pub fn orthogonal_neighbours(&self, cell: Cell) -> impl Iterator<Item = Cell> + '_ {
+        self.offsets(cell, &ORTHOGONAL_NEIGHBOURS)
+    }
+
+    fn offsets(
+        &self,
+        (row, col): Cell,
+        offsets: &'static [Cell],
+    ) -> impl Iterator<Item = Cell> + '_ {
+        offsets
+            .iter()
+            .map(move |(d_row, d_col)| (row + d_row, col + d_col))
+            .filter(|cell| self.contains(*cell))
+    }
+
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Cell> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(cell, _)| cell)
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.cells.len());
+
+        for col in 0..self.width {
+            for row in 0..self.height {
+                cells.push(self.cells[row * self.width + col].clone());
+            }
+        }
+
+        Self::new(self.height, self.width, cells)
+    }
+}
+
+impl Grid<char> {
+    pub fn parse(input: &str) -> Self {
+        Self::parse_with(input, |c| c)
+    }
+}
+
+impl<T> Index<Cell> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, cell: Cell) -> &T {
+        self.get(cell)
+            .unwrap_or_else(|| panic!("Cell {:?} is out of bounds", cell))
+    }
+}
+
+impl<T> IndexMut<Cell> for Grid<T> {
+    fn index_mut(&mut self, cell: Cell) -> &mut T {
+        self.get_mut(cell)
+            .unwrap_or_else(|| panic!("Cell {:?} is out of bounds", cell))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("12.\n#=.");
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(0, 0)], '1');
+        assert_eq!(grid[(1, 1)], '=');
+        assert_eq!(grid.row(1), &['#', '=', '.']);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_parse_ragged_rows() {
+        Grid::parse("123\n45");
+    }
+
+    #[test]
+    fn test_try_parse_with() {
+        let digit = |c: char| c.to_digit(10);
+
+        let grid = Grid::try_parse_with("12\n34", digit, "a digit").unwrap();
+        assert_eq!(grid.row(1), &[3, 4]);
+
+        let error = Grid::try_parse_with("12\n3x", digit, "a digit").unwrap_err();
+        assert_eq!(
+            (error.line, error.span, error.expected.as_str()),
+            (2, 1..2, "a digit")
+        );
+
+        let error = Grid::try_parse_with("12\n345", digit, "a digit").unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (2, "a row of 2 cells")
+        );
+    }
+
+    #[test]
+    fn test_get_out_of_bounds() {
+        let grid = Grid::parse("ab\ncd");
+
+        assert_eq!(grid.get((0, 0)), Some(&'a'));
+        assert_eq!(grid.get((-1, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.get((2, 0)), None);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::parse("...\n...\n...");
+
+        assert_eq!(grid.neighbours((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours((0, 0)).collect::<Vec<Cell>>(),
+            vec![(0, 1), (1, 0), (1, 1)]
+        );
+        assert_eq!(
+            grid.orthogonal_neighbours((0, 2)).collect::<Vec<Cell>>(),
+            vec![(0, 1), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn test_position() {
+        let grid = Grid::parse("..\n.S");
+
+        assert_eq!(grid.position(|c| *c == 'S'), Some((1, 1)));
+        assert_eq!(grid.position(|c| *c == 'X'), None);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let grid = Grid::parse("abc\ndef");
+
+        assert_eq!(grid.transpose(), Grid::parse("ad\nbe\ncf"));
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut grid = Grid::filled(2, 2, false);
+        grid[(1, 0)] = true;
+
+        assert_eq!(
+            grid.iter()
+                .filter(|(_, value)| **value)
+                .map(|(cell, _)| cell)
+                .collect::<Vec<Cell>>(),
+            vec![(1, 0)]
+        );
+    }
+}
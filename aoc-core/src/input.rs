@@ -0,0 +1,99 @@
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Directory holding one `dayNN/input.txt` per day, used when no input path
+/// is given on the command line.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+pub fn read_input(path: impl AsRef<Path>) -> io::Result<String> {
+    fs::read_to_string(path)
+}
+
+/// Where the puzzle input of a run comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Picks the input of `day`: an explicit `path` wins, `-` meaning stdin,
+    /// then `dayNN/input.txt` inside `input_dir`, then `fallback`.
+    pub fn resolve(
+        day: u8,
+        path: Option<&Path>,
+        input_dir: Option<&Path>,
+        fallback: impl Into<PathBuf>,
+    ) -> Self {
+        match (path, input_dir) {
+            (Some(path), _) if path == Path::new("-") => InputSource::Stdin,
+            (Some(path), _) => InputSource::File(path.to_path_buf()),
+            (None, Some(dir)) => {
+                InputSource::File(dir.join(format!("day{:02}", day)).join("input.txt"))
+            }
+            (None, None) => InputSource::File(fallback.into()),
+        }
+    }
+
+    /// Same as `resolve`, with the input directory taken from `AOC_INPUT_DIR`.
+    pub fn from_env(day: u8, path: Option<&Path>, fallback: impl Into<PathBuf>) -> Self {
+        let input_dir = env::var_os(INPUT_DIR_VAR).filter(|dir| !dir.is_empty());
+
+        Self::resolve(day, path, input_dir.as_deref().map(Path::new), fallback)
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            InputSource::File(path) => read_input(path),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let resolve = |path: Option<&str>, dir: Option<&str>| {
+            InputSource::resolve(
+                6,
+                path.map(Path::new),
+                dir.map(Path::new),
+                "day06/input.txt",
+            )
+        };
+
+        assert_eq!(resolve(Some("-"), Some("inputs")), InputSource::Stdin);
+        assert_eq!(
+            resolve(Some("other.txt"), Some("inputs")),
+            InputSource::File(PathBuf::from("other.txt"))
+        );
+        assert_eq!(
+            resolve(None, Some("inputs")),
+            InputSource::File(PathBuf::from("inputs/day06/input.txt"))
+        );
+        assert_eq!(
+            resolve(None, None),
+            InputSource::File(PathBuf::from("day06/input.txt"))
+        );
+        assert_eq!(InputSource::Stdin.to_string(), "<stdin>");
+    }
+}
@@ -0,0 +1,65 @@
+//! Just enough JSON writing for the machine-readable outputs, which only
+//! ever contain flat objects of strings and numbers.
+
+/// Quotes and escapes `value` as a JSON string.
+pub fn string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+
+    escaped.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+
+    escaped
+}
+
+/// Writes `fields` as a single-line JSON object. Values must already be
+/// valid JSON, e.g. numbers or the output of `string`.
+pub fn object(fields: &[(&str, String)]) -> String {
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|(key, value)| format!("{}:{}", string(key), value))
+        .collect();
+
+    format!("{{{}}}", fields.join(","))
+}
+
+pub fn array(values: &[String]) -> String {
+    format!("[{}]", values.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string() {
+        assert_eq!(string("input.txt"), "\"input.txt\"");
+        assert_eq!(string("a\"b\\c\nd\u{1}"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+    }
+
+    #[test]
+    fn test_object() {
+        assert_eq!(
+            object(&[("day", "5".to_string()), ("input", string("input.txt"))]),
+            "{\"day\":5,\"input\":\"input.txt\"}"
+        );
+        assert_eq!(object(&[]), "{}");
+    }
+
+    #[test]
+    fn test_array() {
+        assert_eq!(array(&["1".to_string(), "{}".to_string()]), "[1,{}]");
+    }
+}
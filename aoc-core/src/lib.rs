@@ -0,0 +1,26 @@
+//! Building blocks shared by every day of the calendar: input loading and
+//! caching, typed parse errors, the `Solution` trait each day implements and
+//! the timing and benchmark harness around it, plus the 2D grid of the days
+//! whose input is a map.
+
+pub mod answers;
+pub mod args;
+pub mod bench;
+pub mod cache;
+pub mod error;
+pub mod examples;
+pub mod fetch;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod log;
+pub mod report;
+pub mod solution;
+pub mod timing;
+
+pub use answers::Answers;
+pub use error::{Line, ParseError};
+pub use grid::{Cell, Grid};
+pub use report::Report;
+pub use solution::{run, solve, Day, Part, Solution};
+pub use timing::{solve_timed, Timings};
@@ -0,0 +1,68 @@
+//! Diagnostic output of the solvers. It always goes to stderr, so that stdout
+//! only ever carries answers, and is silent unless the binary was started
+//! with `-v` (debug) or `-vv` (trace).
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+pub const DEBUG: u8 = 1;
+pub const TRACE: u8 = 2;
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_verbosity(level: u8) {
+    VERBOSITY.store(level, Ordering::Relaxed);
+}
+
+pub fn verbosity() -> u8 {
+    VERBOSITY.load(Ordering::Relaxed)
+}
+
+pub fn enabled(level: u8) -> bool {
+    verbosity() >= level
+}
+
+/// Parses a `-v`, `-vv`, ... flag into the verbosity level it stands for.
+pub fn parse_verbosity_flag(flag: &str) -> Option<u8> {
+    let vs = flag.strip_prefix('-')?;
+
+    if !vs.is_empty() && vs.chars().all(|c| c == 'v') {
+        Some(vs.len() as u8)
+    } else {
+        None
+    }
+}
+
+/// Prints to stderr when running with `-v` or more.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::DEBUG) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Prints to stderr when running with `-vv` or more, for per-step output
+/// that would drown everything else.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::TRACE) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_verbosity_flag() {
+        assert_eq!(parse_verbosity_flag("-v"), Some(1));
+        assert_eq!(parse_verbosity_flag("-vvv"), Some(3));
+        assert_eq!(parse_verbosity_flag("-"), None);
+        assert_eq!(parse_verbosity_flag("--verbose"), None);
+        assert_eq!(parse_verbosity_flag("-vx"), None);
+    }
+}
@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+use crate::{json, solution::Part};
+
+/// The answer to one part of a day, as emitted by the `--json` output mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    /// Time spent parsing the input and solving this part.
+    pub elapsed: Duration,
+}
+
+impl Report {
+    /// A single line `{"day":..,"part":..,"answer":..,"elapsed_ms":..}`. The
+    /// answer is always a string since not every puzzle answers a number.
+    pub fn to_json(&self) -> String {
+        json::object(&[
+            ("day", self.day.to_string()),
+            ("part", self.part.number().to_string()),
+            ("answer", json::string(&self.answer)),
+            (
+                "elapsed_ms",
+                format!("{:.3}", self.elapsed.as_secs_f64() * 1000.0),
+            ),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_json() {
+        let report = Report {
+            day: 3,
+            part: Part::Two,
+            answer: "81709807".to_string(),
+            elapsed: Duration::from_micros(1500),
+        };
+
+        assert_eq!(
+            report.to_json(),
+            "{\"day\":3,\"part\":2,\"answer\":\"81709807\",\"elapsed_ms\":1.500}"
+        );
+    }
+}
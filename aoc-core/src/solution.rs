@@ -0,0 +1,207 @@
+use std::{env, fmt::Display, fs, path::Path, process};
+
+use crate::{
+    args::{parse_day_args, USAGE},
+    error::ParseError,
+    input::InputSource,
+    log,
+    report::Report,
+    timing::solve_timed,
+};
+
+/// A day of the calendar: how to parse its puzzle input and how to answer
+/// both parts from the parsed representation.
+pub trait Solution {
+    const DAY: u8;
+
+    /// Parsed puzzle input, which may borrow from the raw text.
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part1(input: &Self::Input<'_>) -> impl Display;
+
+    fn part2(input: &Self::Input<'_>) -> impl Display;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// Parses `input` and answers a single part of `S`.
+pub fn solve<S: Solution>(input: &str, part: Part) -> Result<String, ParseError> {
+    let parsed = S::parse(input)?;
+
+    Ok(match part {
+        Part::One => S::part1(&parsed).to_string(),
+        Part::Two => S::part2(&parsed).to_string(),
+    })
+}
+
+/// A type-erased handle on a `Solution`, so that a runner can keep every day
+/// in a single table and dispatch on the day number.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub solve: fn(&str, Part) -> Result<String, ParseError>,
+}
+
+impl Day {
+    pub fn of<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            solve: solve::<S>,
+        }
+    }
+}
+
+/// Solves both parts of `S` and prints the answers, exiting with a
+/// diagnostic if the input is malformed. The input is the path given on the
+/// command line (`-` for stdin), else `$AOC_INPUT_DIR/dayNN/input.txt`, else
+/// `default_input`. With `--json` the
+/// answers are printed as JSON lines, and with `--timings` the wall time of
+/// each phase is printed as well.
+pub fn run<S: Solution>(default_input: impl AsRef<Path>) {
+    let args = match parse_day_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+
+    log::set_verbosity(args.verbosity);
+
+    let source = InputSource::from_env(S::DAY, args.input.as_deref(), default_input.as_ref());
+
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("Could not read {}: {}", source, error);
+            process::exit(1);
+        }
+    };
+
+    let (answer1, answer2, timings) = match solve_timed::<S>(&input) {
+        Ok(solved) => solved,
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    };
+
+    if args.json {
+        for (part, answer, elapsed) in [
+            (Part::One, answer1, timings.parse + timings.part1),
+            (Part::Two, answer2, timings.parse + timings.part2),
+        ] {
+            let report = Report {
+                day: S::DAY,
+                part,
+                answer,
+                elapsed,
+            };
+
+            println!("{}", report.to_json());
+        }
+
+        // Keep stdout machine-readable.
+        if args.timings {
+            eprintln!("{}", timings);
+        }
+    } else {
+        println!("Answer 1: {}", answer1);
+        println!("Answer 2: {}", answer2);
+
+        if args.timings {
+            println!("\n{}", timings);
+        }
+    }
+
+    if let Some(path) = args.timings_json {
+        if let Err(error) = fs::write(&path, timings.to_json() + "\n") {
+            eprintln!("Could not write {}: {}", path.display(), error);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+
+        type Input<'a> = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            crate::error::lines(input)
+                .map(|line| line.numbers(line.text))
+                .collect::<Result<Vec<Vec<u32>>, ParseError>>()
+                .map(|lines| lines.concat())
+        }
+
+        fn part1(input: &Self::Input<'_>) -> impl Display {
+            input.iter().sum::<u32>()
+        }
+
+        fn part2(input: &Self::Input<'_>) -> impl Display {
+            input.iter().product::<u32>()
+        }
+    }
+
+    #[test]
+    fn test_part_from_number() {
+        assert_eq!(Part::from_number(1), Some(Part::One));
+        assert_eq!(Part::from_number(2), Some(Part::Two));
+        assert_eq!(Part::from_number(3), None);
+        assert_eq!(Part::Two.number(), 2);
+    }
+
+    #[test]
+    fn test_day_of() {
+        let day = Day::of::<Sum>();
+
+        assert_eq!(day.day, 0);
+        assert_eq!((day.solve)("1 2 3 4", Part::One), Ok("10".to_string()));
+        assert_eq!((day.solve)("1 2\n3 4", Part::Two), Ok("24".to_string()));
+    }
+
+    #[test]
+    fn test_solve_parse_error() {
+        let error = solve::<Sum>("1 2\n3 x", Part::One).unwrap_err();
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.span, 2..3);
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = Sum::parse("1 2 3 4").unwrap();
+
+        assert_eq!(Sum::part1(&input).to_string(), "10");
+        assert_eq!(Sum::part2(&input).to_string(), "24");
+    }
+}
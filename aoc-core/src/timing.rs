@@ -0,0 +1,101 @@
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+use crate::{error::ParseError, json, solution::Solution};
+
+/// Wall time of each phase of a single solve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub day: u8,
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+
+    pub fn to_json(&self) -> String {
+        json::object(&[
+            ("day", self.day.to_string()),
+            ("parse_ns", self.parse.as_nanos().to_string()),
+            ("part1_ns", self.part1.as_nanos().to_string()),
+            ("part2_ns", self.part2.as_nanos().to_string()),
+            ("total_ns", self.total().as_nanos().to_string()),
+        ])
+    }
+}
+
+impl fmt::Display for Timings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Parse:  {:.2?}", self.parse)?;
+        writeln!(f, "Part 1: {:.2?}", self.part1)?;
+        writeln!(f, "Part 2: {:.2?}", self.part2)?;
+        write!(f, "Total:  {:.2?}", self.total())
+    }
+}
+
+/// Runs `f` once and returns its result along with how long it took.
+pub fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+
+    (result, start.elapsed())
+}
+
+/// Solves both parts of `S`, timing the parse and each part separately.
+pub fn solve_timed<S: Solution>(input: &str) -> Result<(String, String, Timings), ParseError> {
+    let (parsed, parse) = time(|| S::parse(input));
+    let parsed = parsed?;
+
+    let (answer1, part1) = time(|| S::part1(&parsed).to_string());
+    let (answer2, part2) = time(|| S::part2(&parsed).to_string());
+
+    Ok((
+        answer1,
+        answer2,
+        Timings {
+            day: S::DAY,
+            parse,
+            part1,
+            part2,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::tests::Sum;
+
+    #[test]
+    fn test_solve_timed() {
+        let (answer1, answer2, timings) = solve_timed::<Sum>("1 2 3 4").unwrap();
+
+        assert_eq!((answer1.as_str(), answer2.as_str()), ("10", "24"));
+        assert_eq!(timings.day, 0);
+        assert_eq!(
+            timings.total(),
+            timings.parse + timings.part1 + timings.part2
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        let timings = Timings {
+            day: 5,
+            parse: Duration::from_nanos(1500),
+            part1: Duration::from_micros(2),
+            part2: Duration::from_millis(3),
+        };
+
+        assert_eq!(
+            timings.to_json(),
+            "{\"day\":5,\"parse_ns\":1500,\"part1_ns\":2000,\"part2_ns\":3000000,\"total_ns\":3003500}"
+        );
+    }
+}
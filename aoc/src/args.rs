@@ -0,0 +1,318 @@
+use std::path::PathBuf;
+
+use aoc_core::{log::parse_verbosity_flag, Part};
+
+pub const USAGE: &str = "Usage:
+    aoc run --day <N> [--part <1|2>] [--input <PATH|->] [--json] [-v|-vv]
+    aoc run --all [--part <1|2>] [--json] [-v|-vv]
+    aoc verify [--day <N>]
+    aoc fetch --day <N> | --all [--from <DIR>]
+    aoc new --day <N>";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run(RunOptions),
+    Verify(VerifyOptions),
+    Fetch(FetchOptions),
+    New(NewOptions),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Selection {
+    All,
+    Day(u8),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunOptions {
+    pub selection: Selection,
+    /// Only this part when set, both parts otherwise.
+    pub part: Option<Part>,
+    /// Overrides `$AOC_INPUT_DIR` and the default `dayNN/input.txt`, `-`
+    /// reading from stdin.
+    pub input: Option<PathBuf>,
+    /// Print one JSON object per answer instead of text.
+    pub json: bool,
+    /// How much diagnostic output the solvers write to stderr.
+    pub verbosity: u8,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct VerifyOptions {
+    /// Only this day when set, every day otherwise.
+    pub day: Option<u8>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct FetchOptions {
+    pub selection: Selection,
+    /// Copy inputs from `<DIR>/dayNN/input.txt` instead of downloading them.
+    pub from: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct NewOptions {
+    pub day: u8,
+}
+
+impl RunOptions {
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![Part::One, Part::Two],
+        }
+    }
+}
+
+fn parse_number(flag: &str, value: Option<String>) -> Result<u8, String> {
+    let value = value.ok_or(format!("{} expects a value", flag))?;
+
+    value
+        .parse::<u8>()
+        .map_err(|_| format!("{} expects a number, got '{}'", flag, value))
+}
+
+fn parse_run(args: impl Iterator<Item = String>) -> Result<RunOptions, String> {
+    let mut all = false;
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut json = false;
+    let mut verbosity = 0;
+
+    let mut args = args;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--day" => day = Some(parse_number("--day", args.next())?),
+            "--part" => {
+                let number = parse_number("--part", args.next())?;
+                part = Some(
+                    Part::from_number(number)
+                        .ok_or(format!("--part expects 1 or 2, got {}", number))?,
+                );
+            }
+            "--input" => {
+                input = Some(PathBuf::from(
+                    args.next().ok_or("--input expects a path".to_string())?,
+                ))
+            }
+            "--json" => json = true,
+            flag => match parse_verbosity_flag(flag) {
+                Some(level) => verbosity = level,
+                None => return Err(format!("Unknown argument '{}'", arg)),
+            },
+        }
+    }
+
+    let selection = match (all, day) {
+        (true, None) => Selection::All,
+        (false, Some(day)) => Selection::Day(day),
+        (true, Some(_)) => return Err("--all and --day are mutually exclusive".to_string()),
+        (false, None) => return Err("Either --day or --all is required".to_string()),
+    };
+
+    if selection == Selection::All && input.is_some() {
+        return Err("--input can only be used with a single --day".to_string());
+    }
+
+    Ok(RunOptions {
+        selection,
+        part,
+        input,
+        json,
+        verbosity,
+    })
+}
+
+fn parse_verify(args: impl Iterator<Item = String>) -> Result<VerifyOptions, String> {
+    let mut day = None;
+
+    let mut args = args;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_number("--day", args.next())?),
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        }
+    }
+
+    Ok(VerifyOptions { day })
+}
+
+fn parse_fetch(args: impl Iterator<Item = String>) -> Result<FetchOptions, String> {
+    let mut all = false;
+    let mut day = None;
+    let mut from = None;
+
+    let mut args = args;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--day" => day = Some(parse_number("--day", args.next())?),
+            "--from" => {
+                from = Some(PathBuf::from(
+                    args.next()
+                        .ok_or("--from expects a directory".to_string())?,
+                ))
+            }
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        }
+    }
+
+    let selection = match (all, day) {
+        (true, None) => Selection::All,
+        (false, Some(day)) if (1..=25).contains(&day) => Selection::Day(day),
+        (false, Some(day)) => return Err(format!("--day expects 1 to 25, got {}", day)),
+        (true, Some(_)) => return Err("--all and --day are mutually exclusive".to_string()),
+        (false, None) => return Err("Either --day or --all is required".to_string()),
+    };
+
+    Ok(FetchOptions { selection, from })
+}
+
+fn parse_new(args: impl Iterator<Item = String>) -> Result<NewOptions, String> {
+    let mut day = None;
+
+    let mut args = args;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_number("--day", args.next())?),
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        }
+    }
+
+    match day {
+        Some(day) if (1..=25).contains(&day) => Ok(NewOptions { day }),
+        Some(day) => Err(format!("--day expects 1 to 25, got {}", day)),
+        None => Err("--day is required".to_string()),
+    }
+}
+
+/// Parses the command line, without the program name.
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+
+    match args.next().as_deref() {
+        Some("run") => Ok(Command::Run(parse_run(args)?)),
+        Some("verify") => Ok(Command::Verify(parse_verify(args)?)),
+        Some("fetch") => Ok(Command::Fetch(parse_fetch(args)?)),
+        Some("new") => Ok(Command::New(parse_new(args)?)),
+        Some(command) => Err(format!("Unknown command '{}'", command)),
+        None => Err("Missing command".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Command, String> {
+        parse_args(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_run_day() {
+        assert_eq!(
+            parse("run --day 5 --part 2 --input foo.txt"),
+            Ok(Command::Run(RunOptions {
+                selection: Selection::Day(5),
+                part: Some(Part::Two),
+                input: Some(PathBuf::from("foo.txt")),
+                json: false,
+                verbosity: 0,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_run_all() {
+        assert_eq!(
+            parse("run --all --json -vv"),
+            Ok(Command::Run(RunOptions {
+                selection: Selection::All,
+                part: None,
+                input: None,
+                json: true,
+                verbosity: 2,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        assert_eq!(
+            parse("verify"),
+            Ok(Command::Verify(VerifyOptions { day: None }))
+        );
+        assert_eq!(
+            parse("verify --day 12"),
+            Ok(Command::Verify(VerifyOptions { day: Some(12) }))
+        );
+        assert!(parse("verify --all").is_err());
+    }
+
+    #[test]
+    fn test_parse_fetch() {
+        assert_eq!(
+            parse("fetch --day 13"),
+            Ok(Command::Fetch(FetchOptions {
+                selection: Selection::Day(13),
+                from: None,
+            }))
+        );
+        assert_eq!(
+            parse("fetch --all --from ."),
+            Ok(Command::Fetch(FetchOptions {
+                selection: Selection::All,
+                from: Some(PathBuf::from(".")),
+            }))
+        );
+        assert!(parse("fetch").is_err());
+        assert!(parse("fetch --day 26").is_err());
+        assert!(parse("fetch --day 1 --from").is_err());
+    }
+
+    #[test]
+    fn test_parse_new() {
+        assert_eq!(
+            parse("new --day 13"),
+            Ok(Command::New(NewOptions { day: 13 }))
+        );
+        assert!(parse("new").is_err());
+        assert!(parse("new --day 0").is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("").is_err());
+        assert!(parse("fly").is_err());
+        assert!(parse("run").is_err());
+        assert!(parse("run --day").is_err());
+        assert!(parse("run --day five").is_err());
+        assert!(parse("run --day 1 --part 3").is_err());
+        assert!(parse("run --all --day 1").is_err());
+        assert!(parse("run --all --input foo.txt").is_err());
+        assert!(parse("run --day 1 --verbose").is_err());
+    }
+
+    #[test]
+    fn test_parts() {
+        let mut options = RunOptions {
+            selection: Selection::Day(1),
+            part: None,
+            input: None,
+            json: false,
+            verbosity: 0,
+        };
+
+        assert_eq!(options.parts(), vec![Part::One, Part::Two]);
+
+        options.part = Some(Part::Two);
+
+        assert_eq!(options.parts(), vec![Part::Two]);
+    }
+}
@@ -0,0 +1,52 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+};
+
+use aoc_core::{Day, ParseError, Part};
+
+pub fn days() -> Vec<Day> {
+    vec![
+        Day::of::<day01::Day01>(),
+        Day::of::<day02::Day02>(),
+        Day::of::<day03::Day03>(),
+        Day::of::<day04::Day04>(),
+        Day::of::<day05::Day05>(),
+        Day::of::<day06::Day06>(),
+        Day::of::<day07::Day07>(),
+        Day::of::<day08::Day08>(),
+        Day::of::<day09::Day09>(),
+        Day::of::<day10::Day10>(),
+        Day::of::<day11::Day11>(),
+        Day::of::<day12::Day12>(),
+    ]
+}
+
+pub fn find_day(day: u8) -> Option<Day> {
+    days().into_iter().find(|d| d.day == day)
+}
+
+/// The crate directory of `day`, relative to the workspace root.
+pub fn day_dir(day: u8) -> PathBuf {
+    PathBuf::from(format!("day{:02}", day))
+}
+
+pub fn default_input(day: u8) -> PathBuf {
+    day_dir(day).join("input.txt")
+}
+
+#[derive(Debug)]
+pub enum Failure {
+    Parse(ParseError),
+    Panic,
+}
+
+/// Solves one part of `day`, turning a panicking solver into a `Failure`
+/// so that a single broken day cannot take the whole run down.
+pub fn solve_part(day: &Day, input: &str, part: Part) -> Result<String, Failure> {
+    match panic::catch_unwind(AssertUnwindSafe(|| (day.solve)(input, part))) {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(error)) => Err(Failure::Parse(error)),
+        Err(_) => Err(Failure::Panic),
+    }
+}
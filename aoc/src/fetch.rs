@@ -0,0 +1,71 @@
+use aoc_core::{
+    cache::{default_cache_dir, InputCache},
+    fetch::{DirFetcher, FetchError, Fetcher, HttpFetcher, Session, YEAR},
+};
+
+use crate::{
+    args::{FetchOptions, Selection},
+    days,
+};
+
+/// Makes sure the input of every day in `days` is cached, printing where.
+/// Returns whether all of them are.
+fn fetch_days(cache: &InputCache<impl Fetcher>, days: &[u8]) -> bool {
+    let mut success = true;
+
+    for &day in days {
+        let cached = cache.is_cached(YEAR, day);
+
+        match cache.get(YEAR, day) {
+            Ok(path) if cached => println!("Day {:02}: cached at {}", day, path.display()),
+            Ok(path) => println!("Day {:02}: fetched to {}", day, path.display()),
+            Err(error) => {
+                eprintln!("Day {:02}: {}", day, error);
+                success = false;
+            }
+        }
+    }
+
+    success
+}
+
+/// Fills the input cache, from the website unless `--from` names a local
+/// directory. The session token is only required for days not cached yet.
+pub fn fetch(options: FetchOptions) -> bool {
+    let days: Vec<u8> = match options.selection {
+        Selection::All => days::days().iter().map(|day| day.day).collect(),
+        Selection::Day(day) => vec![day],
+    };
+
+    let dir = match default_cache_dir() {
+        Ok(dir) => dir,
+        Err(error) => {
+            eprintln!("{}", error);
+            return false;
+        }
+    };
+
+    if let Some(from) = options.from {
+        return fetch_days(&InputCache::new(dir, DirFetcher::new(from)), &days);
+    }
+
+    match Session::from_env() {
+        Ok(session) => fetch_days(&InputCache::new(dir, HttpFetcher::new(session)), &days),
+        // Days already cached are still served without a token.
+        Err(FetchError::MissingSession) => fetch_days(&InputCache::new(dir, NoSession), &days),
+        Err(error) => {
+            eprintln!("Could not read the session token: {}", error);
+            false
+        }
+    }
+}
+
+/// Stands in for the website when there is no session token, so that only
+/// the days missing from the cache fail.
+struct NoSession;
+
+impl Fetcher for NoSession {
+    fn fetch(&self, _year: u16, _day: u8) -> Result<String, FetchError> {
+        Err(FetchError::MissingSession)
+    }
+}
@@ -0,0 +1,102 @@
+mod args;
+mod days;
+mod fetch;
+mod scaffold;
+mod verify;
+
+use std::{env, process::ExitCode, time::Instant};
+
+use aoc_core::{input::InputSource, log, Day, Part, Report};
+use args::{parse_args, Command, RunOptions, Selection, USAGE};
+use days::{default_input, solve_part, Failure};
+
+/// Runs the requested parts of `day`, printing one line per part, as JSON
+/// when `json` is set. Returns whether every part produced an answer.
+fn run_day(day: &Day, parts: &[Part], source: InputSource, json: bool) -> bool {
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("Day {:02}: could not read {}: {}", day.day, source, error);
+            return false;
+        }
+    };
+
+    let mut success = true;
+
+    for part in parts {
+        let start = Instant::now();
+
+        match solve_part(day, &input, *part) {
+            Ok(answer) if json => {
+                let report = Report {
+                    day: day.day,
+                    part: *part,
+                    answer,
+                    elapsed: start.elapsed(),
+                };
+
+                println!("{}", report.to_json());
+            }
+            Ok(answer) => println!("Day {:02} part {}: {}", day.day, part.number(), answer),
+            Err(Failure::Parse(error)) => {
+                // Both parts share the parser, so there is no point in trying
+                // the other one.
+                eprintln!("Day {:02}: could not parse input\n{}", day.day, error);
+                return false;
+            }
+            Err(Failure::Panic) => {
+                eprintln!("Day {:02} part {}: solver panicked", day.day, part.number());
+                success = false;
+            }
+        }
+    }
+
+    success
+}
+
+fn run(options: RunOptions) -> bool {
+    let parts = options.parts();
+
+    log::set_verbosity(options.verbosity);
+
+    match options.selection {
+        Selection::All => days::days().iter().fold(true, |success, day| {
+            let source = InputSource::from_env(day.day, None, default_input(day.day));
+            run_day(day, &parts, source, options.json) && success
+        }),
+        Selection::Day(number) => match days::find_day(number) {
+            Some(day) => {
+                let source =
+                    InputSource::from_env(number, options.input.as_deref(), default_input(number));
+                run_day(&day, &parts, source, options.json)
+            }
+            None => {
+                eprintln!("Day {} is not solved yet", number);
+                false
+            }
+        },
+    }
+}
+
+fn main() -> ExitCode {
+    let command = match parse_args(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let success = match command {
+        Command::Run(options) => run(options),
+        Command::Verify(options) => verify::verify(options),
+        Command::Fetch(options) => fetch::fetch(options),
+        Command::New(options) => scaffold::new_day(options),
+    };
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,244 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{args::NewOptions, days::day_dir};
+
+const CARGO_TOML: &str = r#"[package]
+name = "dayNN"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc-core = { path = "../aoc-core" }
+
+[[bench]]
+name = "bench"
+harness = false
+"#;
+
+const LIB_RS: &str = r#"use aoc_core::{error::lines, Line, ParseError, Solution};
+use std::fmt::Display;
+
+fn parse_line(line: Line<'_>) -> Result<&str, ParseError> {
+    Ok(line.text)
+}
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    const DAY: u8 = N;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        lines(input).map(parse_line).collect()
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> impl Display {
+        lines.len()
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> impl Display {
+        lines.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::example_tests!(DayNN);
+}
+"#;
+
+const MAIN_RS: &str = r#"use dayNN::DayNN;
+
+fn main() {
+    aoc_core::run::<DayNN>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+}
+"#;
+
+const BENCH_RS: &str = r#"fn main() {
+    aoc_core::bench::main::<dayNN::DayNN>(env!("CARGO_MANIFEST_DIR"));
+}
+"#;
+
+const ANSWERS_TOML: &str = r#"["input.txt"]
+# part1 =
+# part2 =
+"#;
+
+/// Fails the example tests until the puzzle example and its answer are in.
+const EXAMPLE_TXT: &str = "part1: TODO
+---
+";
+
+/// Fills in the day number of a template: `dayNN`/`DayNN` become the crate
+/// and type names, and `N` alone the number itself.
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("NN", &format!("{:02}", day))
+        .replace("const DAY: u8 = N;", &format!("const DAY: u8 = {};", day))
+}
+
+/// The files of a new day crate, relative to the crate directory.
+pub fn files(day: u8) -> Vec<(&'static str, String)> {
+    vec![
+        ("Cargo.toml", render(CARGO_TOML, day)),
+        ("src/lib.rs", render(LIB_RS, day)),
+        ("src/main.rs", render(MAIN_RS, day)),
+        ("benches/bench.rs", render(BENCH_RS, day)),
+        ("answers.toml", ANSWERS_TOML.to_string()),
+        ("examples/example.txt", EXAMPLE_TXT.to_string()),
+        ("input.txt", String::new()),
+    ]
+}
+
+/// Inserts `line` after the last line of `text` containing `marker`, which
+/// is how the runner's day list and dependencies are kept in order.
+fn insert_after_last(text: &str, marker: &str, line: &str) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let last = lines.iter().rposition(|l| l.contains(marker))?;
+
+    let indent: String = lines[last]
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .collect();
+
+    let mut lines: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    lines.insert(last + 1, format!("{}{}", indent, line));
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Registers the new day with the `aoc` runner, so that it can be run and
+/// verified like the others.
+fn register(root: &Path, day: u8) -> io::Result<()> {
+    let edits = [
+        (
+            root.join("aoc/Cargo.toml"),
+            "= { path = \"../day",
+            format!("day{0:02} = {{ path = \"../day{0:02}\" }}", day),
+        ),
+        (
+            root.join("aoc/src/days.rs"),
+            "Day::of::<day",
+            format!("Day::of::<day{0:02}::Day{0:02}>(),", day),
+        ),
+    ];
+
+    for (path, marker, line) in edits {
+        let text = fs::read_to_string(&path)?;
+
+        let text = insert_after_last(&text, marker, &line).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} has no '{}' line", path.display(), marker),
+            )
+        })?;
+
+        fs::write(&path, text)?;
+    }
+
+    Ok(())
+}
+
+/// Creates `dayNN` in `root`, refusing to touch an existing directory.
+pub fn create(root: &Path, day: u8) -> io::Result<PathBuf> {
+    let dir = root.join(day_dir(day));
+
+    if dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", dir.display()),
+        ));
+    }
+
+    for (name, content) in files(day) {
+        let path = dir.join(name);
+
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(path, content)?;
+    }
+
+    register(root, day)?;
+
+    Ok(dir)
+}
+
+/// Generates a new day crate in the workspace root.
+pub fn new_day(options: NewOptions) -> bool {
+    match create(Path::new("."), options.day) {
+        Ok(dir) => {
+            println!("Created {}", dir.display());
+            println!("Put the puzzle example and its answers in examples/example.txt.");
+            true
+        }
+        Err(error) => {
+            eprintln!("Could not create day {}: {}", options.day, error);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    #[test]
+    fn test_render() {
+        let files = files(13);
+        let file = |name| &files.iter().find(|(n, _)| *n == name).unwrap().1;
+
+        assert!(file("Cargo.toml").contains("name = \"day13\""));
+        assert!(file("src/lib.rs").contains("pub struct Day13;"));
+        assert!(file("src/lib.rs").contains("const DAY: u8 = 13;"));
+        assert!(file("src/main.rs").starts_with("use day13::Day13;"));
+        assert!(file("benches/bench.rs").contains("day13::Day13"));
+        assert!(file("input.txt").is_empty());
+    }
+
+    #[test]
+    fn test_insert_after_last() {
+        let days = "vec![\n    Day::of::<day01::Day01>(),\n    Day::of::<day02::Day02>(),\n]\n";
+
+        assert_eq!(
+            insert_after_last(days, "Day::of::<day", "Day::of::<day03::Day03>(),").unwrap(),
+            "vec![\n    Day::of::<day01::Day01>(),\n    Day::of::<day02::Day02>(),\n    Day::of::<day03::Day03>(),\n]\n"
+        );
+        assert_eq!(insert_after_last(days, "nowhere", "x"), None);
+    }
+
+    #[test]
+    fn test_create() {
+        let root = env::temp_dir().join(format!("aoc-new-{}", process::id()));
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[dependencies]\nday01 = { path = \"../day01\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/src/days.rs"),
+            "vec![\n    Day::of::<day01::Day01>(),\n]\n",
+        )
+        .unwrap();
+
+        let dir = create(&root, 13).unwrap();
+        let error = create(&root, 13).unwrap_err();
+        let cargo_toml = fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap();
+        let example = dir.join("examples/example.txt").is_file();
+
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(dir, root.join("day13"));
+        assert!(example);
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+        assert!(cargo_toml.ends_with("day13 = { path = \"../day13\" }\n"));
+    }
+}
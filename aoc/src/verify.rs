@@ -0,0 +1,251 @@
+use std::fmt;
+
+use aoc_core::{input::read_input, Answers, Day, Part};
+
+use crate::{
+    args::VerifyOptions,
+    days::{self, day_dir, solve_part, Failure},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+        };
+
+        f.pad(status)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub day: u8,
+    pub input: String,
+    pub part: Option<Part>,
+    pub expected: Option<String>,
+    pub actual: String,
+    pub status: Status,
+}
+
+impl Row {
+    fn missing(day: u8, input: &str, part: Option<Part>, reason: &str) -> Self {
+        Self {
+            day,
+            input: input.to_string(),
+            part,
+            expected: None,
+            actual: reason.to_string(),
+            status: Status::Missing,
+        }
+    }
+}
+
+/// Checks one registered answer against what the solver currently returns.
+fn check(day: &Day, input_name: &str, input: &str, part: Part, expected: &str) -> Row {
+    let (actual, status) = match solve_part(day, input, part) {
+        Ok(answer) if answer == expected => (answer, Status::Pass),
+        Ok(answer) => (answer, Status::Fail),
+        Err(Failure::Parse(error)) => {
+            eprintln!("Day {:02} {}\n{}", day.day, input_name, error);
+            ("parse error".to_string(), Status::Fail)
+        }
+        Err(Failure::Panic) => ("panicked".to_string(), Status::Fail),
+    };
+
+    Row {
+        day: day.day,
+        input: input_name.to_string(),
+        part: Some(part),
+        expected: Some(expected.to_string()),
+        actual,
+        status,
+    }
+}
+
+fn verify_day(day: &Day) -> Vec<Row> {
+    let answers = match Answers::load(day_dir(day.day).join("answers.toml")) {
+        Ok(Some(Ok(answers))) => answers,
+        Ok(Some(Err(error))) => {
+            eprintln!("Day {:02} answers.toml\n{}", day.day, error);
+            return vec![Row {
+                status: Status::Fail,
+                ..Row::missing(day.day, "answers.toml", None, "parse error")
+            }];
+        }
+        Ok(None) => return vec![Row::missing(day.day, "-", None, "no answers.toml")],
+        Err(error) => {
+            return vec![Row {
+                status: Status::Fail,
+                ..Row::missing(day.day, "answers.toml", None, &error.to_string())
+            }]
+        }
+    };
+
+    let mut rows = Vec::new();
+
+    for input_name in answers.inputs() {
+        let input = match read_input(day_dir(day.day).join(input_name)) {
+            Ok(input) => input,
+            Err(_) => {
+                rows.push(Row::missing(day.day, input_name, None, "no input file"));
+                continue;
+            }
+        };
+
+        for part in [Part::One, Part::Two] {
+            rows.push(match answers.get(input_name, part) {
+                Some(expected) => check(day, input_name, &input, part, expected),
+                None => Row::missing(day.day, input_name, Some(part), "-"),
+            });
+        }
+    }
+
+    rows
+}
+
+pub fn render(rows: &[Row]) -> String {
+    let header = ["Day", "Input", "Part", "Expected", "Actual", "Status"];
+
+    let cells: Vec<[String; 6]> = rows
+        .iter()
+        .map(|row| {
+            [
+                format!("{:02}", row.day),
+                row.input.clone(),
+                row.part
+                    .map_or("-".to_string(), |part| part.number().to_string()),
+                row.expected.clone().unwrap_or("-".to_string()),
+                row.actual.clone(),
+                row.status.to_string(),
+            ]
+        })
+        .collect();
+
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| {
+            cells
+                .iter()
+                .map(|row| row[i].len())
+                .chain(std::iter::once(header[i].len()))
+                .max()
+                .unwrap()
+        })
+        .collect();
+
+    let format_row = |row: Vec<&str>| {
+        row.iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<String>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut lines = vec![format_row(header.to_vec())];
+
+    for row in cells.iter() {
+        lines.push(format_row(row.iter().map(|cell| cell.as_str()).collect()));
+    }
+
+    lines.join("\n")
+}
+
+/// Runs every solver against every input registered in its `answers.toml`
+/// and prints a pass/fail/missing table. Returns whether nothing failed.
+pub fn verify(options: VerifyOptions) -> bool {
+    let days: Vec<Day> = match options.day {
+        Some(number) => match days::find_day(number) {
+            Some(day) => vec![day],
+            None => {
+                eprintln!("Day {} is not solved yet", number);
+                return false;
+            }
+        },
+        None => days::days(),
+    };
+
+    let rows: Vec<Row> = days.iter().flat_map(verify_day).collect();
+
+    println!("{}", render(&rows));
+
+    let count = |status: Status| rows.iter().filter(|row| row.status == status).count();
+
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Missing)
+    );
+
+    count(Status::Fail) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{ParseError, Solution};
+    use std::fmt::Display;
+
+    struct Double;
+
+    impl Solution for Double {
+        const DAY: u8 = 99;
+
+        type Input<'a> = u32;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            let line = aoc_core::Line::new(1, input.trim());
+            line.number(line.text)
+        }
+
+        fn part1(input: &Self::Input<'_>) -> impl Display {
+            input * 2
+        }
+
+        fn part2(input: &Self::Input<'_>) -> impl Display {
+            input.checked_sub(100).expect("not solved yet")
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let day = Day::of::<Double>();
+
+        assert_eq!(check(&day, "a", "21", Part::One, "42").status, Status::Pass);
+        assert_eq!(check(&day, "a", "21", Part::One, "41").status, Status::Fail);
+        assert_eq!(check(&day, "a", "x", Part::One, "42").actual, "parse error");
+        assert_eq!(check(&day, "a", "21", Part::Two, "0").actual, "panicked");
+    }
+
+    #[test]
+    fn test_render() {
+        let rows = vec![
+            Row {
+                day: 1,
+                input: "input.txt".to_string(),
+                part: Some(Part::One),
+                expected: Some("55002".to_string()),
+                actual: "55002".to_string(),
+                status: Status::Pass,
+            },
+            Row::missing(10, "-", None, "no answers.toml"),
+        ];
+
+        assert_eq!(
+            render(&rows),
+            "Day  Input      Part  Expected  Actual           Status
+01   input.txt  1     55002     55002            pass
+10   -          -     -         no answers.toml  missing"
+        );
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_core::bench::main::<day01::Day01>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,144 @@
+//! How each calibration value was recovered: which tokens were taken as the
+//! first and last digits and where they are, so that a surprising total can
+//! be traced back to the lines behind it.
+
+use std::{fmt, ops::Range};
+
+use aoc_core::{error::lines, Line, ParseError};
+
+use crate::scanner::{Match, Scanner};
+
+/// A digit found in a line, as written there: `"7"`, `"seven"`...
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub text: &'a str,
+    pub value: u32,
+    /// Byte range of the token in the line, always on char boundaries.
+    pub span: Range<usize>,
+    /// The same range counted in chars, which is where the token shows up
+    /// for a reader of a non-ASCII line.
+    pub chars: Range<usize>,
+}
+
+impl<'a> Token<'a> {
+    fn new(line: &'a str, m: Match) -> Self {
+        let text = &line[m.span.clone()];
+        let start = line[..m.span.start].chars().count();
+
+        Self {
+            text,
+            value: m.value,
+            chars: start..start + text.chars().count(),
+            span: m.span,
+        }
+    }
+}
+
+impl fmt::Display for Token<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "'{}' ({}) at {}..{}",
+            self.text, self.value, self.chars.start, self.chars.end
+        )
+    }
+}
+
+/// The calibration value of a line and the tokens it was made of.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Extraction<'a> {
+    /// 1-based line number.
+    pub line: usize,
+    pub first: Token<'a>,
+    pub last: Token<'a>,
+    pub value: u32,
+}
+
+impl fmt::Display for Extraction<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: first {}, last {} -> {}",
+            self.line, self.first, self.last, self.value
+        )
+    }
+}
+
+/// Recovers the calibration value of `line`, a line without any digit being
+/// an error rather than a value of 0.
+pub fn extract<'a>(scanner: &Scanner, line: Line<'a>) -> Result<Extraction<'a>, ParseError> {
+    let (first, last) = scanner
+        .first_and_last(line.text)
+        .ok_or_else(|| line.error(line.text, "a digit"))?;
+
+    let first = Token::new(line.text, first);
+    let last = Token::new(line.text, last);
+
+    Ok(Extraction {
+        line: line.number,
+        value: first.value * 10 + last.value,
+        first,
+        last,
+    })
+}
+
+/// One extraction, or the reason there is none, for every line of `input`.
+pub fn explain<'a>(
+    scanner: &'a Scanner,
+    input: &'a str,
+) -> impl Iterator<Item = Result<Extraction<'a>, ParseError>> + 'a {
+    lines(input).map(move |line| extract(scanner, line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vocabulary::Vocabulary;
+
+    #[test]
+    fn test_extract() {
+        let scanner = Vocabulary::digits().with(Vocabulary::english()).scanner();
+        let extraction = extract(&scanner, Line::new(3, "xtwone3four")).unwrap();
+
+        assert_eq!(
+            extraction.first,
+            Token {
+                text: "two",
+                value: 2,
+                span: 1..4,
+                chars: 1..4,
+            }
+        );
+        assert_eq!(extraction.last.span, 7..11);
+        assert_eq!(extraction.value, 24);
+        assert_eq!(
+            extraction.to_string(),
+            "line 3: first 'two' (2) at 1..4, last 'four' (4) at 7..11 -> 24"
+        );
+    }
+
+    #[test]
+    fn test_explain() {
+        let scanner = Vocabulary::digits().scanner();
+        let report: Vec<_> = explain(&scanner, "treb7uchet\nnothing\n").collect();
+
+        let extraction = report[0].as_ref().unwrap();
+        assert_eq!(extraction.first, extraction.last);
+        assert_eq!(extraction.value, 77);
+
+        let error = report[1].as_ref().unwrap_err();
+        assert_eq!((error.line, error.span.clone()), (2, 0..7));
+        assert_eq!(error.expected, "a digit");
+    }
+
+    #[test]
+    fn test_extract_non_ascii() {
+        let scanner = Vocabulary::unicode_digits().scanner();
+        let extraction = extract(&scanner, Line::new(1, "é٣ab३")).unwrap();
+
+        assert_eq!((extraction.first.text, extraction.first.span), ("٣", 2..4));
+        assert_eq!(extraction.first.chars, 1..2);
+        assert_eq!((extraction.last.span, extraction.last.chars), (6..9, 4..5));
+        assert_eq!(extraction.value, 33);
+    }
+}
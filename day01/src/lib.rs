@@ -0,0 +1,116 @@
+pub mod explain;
+pub mod scanner;
+pub mod vocabulary;
+
+use aoc_core::{ParseError, Solution};
+use scanner::Scanner;
+use std::{fmt::Display, sync::OnceLock};
+use vocabulary::Vocabulary;
+
+fn digit_scanner() -> &'static Scanner {
+    static SCANNER: OnceLock<Scanner> = OnceLock::new();
+
+    SCANNER.get_or_init(|| Vocabulary::unicode_digits().scanner())
+}
+
+fn spelled_digit_scanner() -> &'static Scanner {
+    static SCANNER: OnceLock<Scanner> = OnceLock::new();
+
+    SCANNER.get_or_init(|| {
+        Vocabulary::unicode_digits()
+            .with(Vocabulary::english())
+            .scanner()
+    })
+}
+
+/// The first digit found in `line` followed by the last one, if any. Build
+/// `scanner` from a `Vocabulary` to choose which words count as digits.
+pub fn calibration_value(scanner: &Scanner, line: &str) -> Option<u32> {
+    scanner
+        .first_and_last(line)
+        .map(|(first, last)| first.value * 10 + last.value)
+}
+
+/// Lines only spelling their digits out are worth nothing in part 1.
+fn get_line_value(line: &str) -> u32 {
+    calibration_value(digit_scanner(), line).unwrap_or(0)
+}
+
+/// Lines without any digit are worth nothing either; `--explain` is the
+/// place to spot them.
+fn get_line_value_2(line: &str) -> u32 {
+    calibration_value(spelled_digit_scanner(), line).unwrap_or(0)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        input.iter().fold(0, |acc, line| get_line_value(line) + acc)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        input
+            .iter()
+            .fold(0, |acc, line| get_line_value_2(line) + acc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::example_tests!(Day01);
+
+    #[test]
+    fn test_get_line_value() {
+        assert_eq!(get_line_value("1abc2"), 12);
+        assert_eq!(get_line_value("pqr3stu8vwx"), 38);
+        assert_eq!(get_line_value("a1b2c3d4e5f"), 15);
+        assert_eq!(get_line_value("treb7uchet"), 77);
+        assert_eq!(get_line_value("blabla12blabla345"), 15);
+        assert_eq!(get_line_value("54321"), 51);
+        assert_eq!(get_line_value("abc123"), 13);
+        assert_eq!(get_line_value("123abc"), 13);
+    }
+
+    #[test]
+    fn test_get_line_value_2() {
+        assert_eq!(get_line_value_2("two1nine"), 29);
+        assert_eq!(get_line_value_2("eightwothree"), 83);
+        assert_eq!(get_line_value_2("abcone2threexyz"), 13);
+        assert_eq!(get_line_value_2("xtwone3four"), 24);
+        assert_eq!(get_line_value_2("4nineeightseven2"), 42);
+        assert_eq!(get_line_value_2("zoneight234"), 14);
+        assert_eq!(get_line_value_2("7pqrstsixteen"), 76);
+        assert_eq!(get_line_value_2("rrd8rrfcvzhcdr651rfc6h"), 86);
+        assert_eq!(get_line_value_2("oneight"), 18);
+        assert_eq!(get_line_value_2("eightwo"), 82);
+    }
+
+    #[test]
+    fn test_unicode_digits() {
+        assert_eq!(get_line_value("a٣b७c"), 37);
+        assert_eq!(get_line_value_2("one２"), 12);
+
+        let input = Day01::parse("٣x\nfour３\n").unwrap();
+        assert_eq!(Day01::part1(&input).to_string(), "66");
+        assert_eq!(Day01::part2(&input).to_string(), "76");
+    }
+
+    #[test]
+    fn test_lines_without_digits() {
+        let input = Day01::parse("two1nine\nabcdef\n").unwrap();
+
+        assert_eq!(Day01::part1(&input).to_string(), "11");
+        assert_eq!(Day01::part2(&input).to_string(), "29");
+    }
+}
@@ -1,114 +1,88 @@
-use std::collections::HashMap;
-use std::format;
-use std::fs;
-
-fn get_line_value(line: &str) -> i32 {
-    let digits: Vec<char> = line.chars().filter(|c| c.is_digit(10)).collect();
-    let first_digit = digits.first().unwrap_or(&'0');
-    let last_digit = digits.last().unwrap_or(&'0');
-
-    format!("{}{}", first_digit, last_digit)
-        .parse::<i32>()
-        .expect("Could not parse line value")
-}
-
-fn get_line_value_2(line: &str) -> i32 {
-    let mapping = HashMap::from([
-        ("1", '1'),
-        ("2", '2'),
-        ("3", '3'),
-        ("4", '4'),
-        ("5", '5'),
-        ("6", '6'),
-        ("7", '7'),
-        ("8", '8'),
-        ("9", '9'),
-        ("one", '1'),
-        ("two", '2'),
-        ("three", '3'),
-        ("four", '4'),
-        ("five", '5'),
-        ("six", '6'),
-        ("seven", '7'),
-        ("eight", '8'),
-        ("nine", '9'),
-    ]);
-
-    let to_match: Vec<&str> = mapping.keys().cloned().collect();
-
-    let mut min_index = usize::MAX;
-    let mut max_index = usize::MIN;
-
-    let mut first_digit = 'n';
-    let mut last_digit = 'n';
-
-    for word in to_match.iter() {
-        match line.find(word) {
-            Some(index) => {
-                if index <= min_index {
-                    min_index = index;
-                    first_digit = *mapping.get(word).expect("Could not get mapping");
-                }
+use std::{env, path::Path, process};
+
+use aoc_core::input::InputSource;
+use day01::{explain::explain, vocabulary::Vocabulary, Day01};
+
+const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+const EXPLAIN_USAGE: &str = "Usage: day01 --explain [--unicode] [--vocabulary <PATH>] [<INPUT>|-]";
+
+/// Prints how the calibration value of every line was recovered, with the
+/// part 2 digits unless a vocabulary file is given. `--unicode` adds the
+/// decimal digits of every script to that file's words. Ends with the sum of the values found, the
+/// answer this vocabulary gives. Returns whether every line had a digit.
+fn run_explain(args: &[String]) -> Result<bool, String> {
+    let mut vocabulary = None;
+    let mut unicode = false;
+    let mut input = None;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--unicode" => unicode = true,
+            "--vocabulary" => {
+                let path = args.next().ok_or("--vocabulary expects a path")?;
+
+                vocabulary = Some(
+                    Vocabulary::load(path)
+                        .map_err(|error| format!("Could not read {}: {}", path, error))?
+                        .map_err(|error| format!("{}\n{}", path, error))?,
+                );
             }
-            None => {}
+            path if input.is_none() && (path == "-" || !path.starts_with('-')) => {
+                input = Some(Path::new(path));
+            }
+            _ => return Err(format!("Unknown argument '{}'\n\n{}", arg, EXPLAIN_USAGE)),
         }
+    }
 
-        match line.rfind(word) {
-            Some(index) => {
-                if index >= max_index {
-                    max_index = index;
-                    last_digit = *mapping.get(word).expect("Could not get mapping");
-                }
+    let source = InputSource::from_env(1, input, DEFAULT_INPUT);
+    let text = source
+        .read()
+        .map_err(|error| format!("Could not read {}: {}", source, error))?;
+
+    let vocabulary = match vocabulary {
+        Some(vocabulary) if unicode => vocabulary.with(Vocabulary::unicode_digits()),
+        Some(vocabulary) => vocabulary,
+        None => Vocabulary::unicode_digits().with(Vocabulary::english()),
+    };
+    let scanner = vocabulary.scanner();
+    let mut valid = true;
+    let mut sum = 0u64;
+
+    for extraction in explain(&scanner, &text) {
+        match extraction {
+            Ok(extraction) => {
+                println!("{}", extraction);
+                sum += extraction.value as u64;
+            }
+            Err(error) => {
+                println!("{}", error);
+                valid = false;
             }
-            None => {}
         }
     }
 
-    format!("{}{}", first_digit, last_digit)
-        .parse::<i32>()
-        .expect("Could not parse line value")
-}
-
-fn main() {
-    let input = fs::read_to_string("./input.txt").unwrap();
-
-    let answer1 = input
-        .lines()
-        .fold(0, |acc, line| get_line_value(line) + acc);
+    println!("Sum: {}", sum);
 
-    let answer2 = input
-        .lines()
-        .fold(0, |acc, line| get_line_value_2(line) + acc);
-
-    println!("Answer 1: {}", answer1);
-    println!("Answer 2: {}", answer2);
+    Ok(valid)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if args.first().is_some_and(|arg| arg == "--explain") {
+        match run_explain(&args[1..]) {
+            Ok(true) => {}
+            Ok(false) => process::exit(1),
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(2);
+            }
+        }
 
-    #[test]
-    fn test_get_line_value() {
-        assert_eq!(get_line_value("1abc2"), 12);
-        assert_eq!(get_line_value("pqr3stu8vwx"), 38);
-        assert_eq!(get_line_value("a1b2c3d4e5f"), 15);
-        assert_eq!(get_line_value("treb7uchet"), 77);
-        assert_eq!(get_line_value("blabla12blabla345"), 15);
-        assert_eq!(get_line_value("54321"), 51);
-        assert_eq!(get_line_value("abc123"), 13);
-        assert_eq!(get_line_value("123abc"), 13);
+        return;
     }
 
-    #[test]
-    fn test_get_line_value_2() {
-        assert_eq!(get_line_value_2("two1nine"), 29);
-        assert_eq!(get_line_value_2("eightwothree"), 83);
-        assert_eq!(get_line_value_2("abcone2threexyz"), 13);
-        assert_eq!(get_line_value_2("xtwone3four"), 24);
-        assert_eq!(get_line_value_2("4nineeightseven2"), 42);
-        assert_eq!(get_line_value_2("zoneight234"), 14);
-        assert_eq!(get_line_value_2("7pqrstsixteen"), 76);
-        assert_eq!(get_line_value_2("rrd8rrfcvzhcdr651rfc6h"), 88);
-    }
+    aoc_core::run::<Day01>(DEFAULT_INPUT);
 }
@@ -0,0 +1,197 @@
+//! An Aho–Corasick automaton finding every occurrence of a set of patterns
+//! in a single pass, overlapping ones included: `eightwo` holds both `eight`
+//! and `two`.
+
+use std::{collections::VecDeque, ops::Range};
+
+const ROOT: usize = 0;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// The value of the matched pattern.
+    pub value: u32,
+    /// Byte range of the occurrence in the scanned text.
+    pub span: Range<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    /// Next state for every byte, failure transitions included.
+    next: Box<[usize; 256]>,
+    /// Patterns ending in this state, as indexes into `Scanner::patterns`,
+    /// including those ending in the states of its failure chain.
+    outputs: Vec<usize>,
+}
+
+impl State {
+    fn new() -> Self {
+        Self {
+            next: Box::new([ROOT; 256]),
+            outputs: Vec::new(),
+        }
+    }
+}
+
+/// Finds patterns, each carrying a value, in texts. Built once and reused for
+/// every line.
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    states: Vec<State>,
+    /// Byte length and value of every pattern.
+    patterns: Vec<(usize, u32)>,
+}
+
+impl Scanner {
+    pub fn new<'a>(patterns: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        let mut scanner = Scanner {
+            states: vec![State::new()],
+            patterns: Vec::new(),
+        };
+
+        // `next` only holds trie edges until the failure links are computed,
+        // a missing edge pointing back to the root.
+        for (pattern, value) in patterns {
+            assert!(!pattern.is_empty(), "Cannot scan for an empty pattern");
+
+            let mut state = ROOT;
+
+            for &byte in pattern.as_bytes() {
+                state = match scanner.states[state].next[byte as usize] {
+                    ROOT => {
+                        scanner.states.push(State::new());
+                        let new_state = scanner.states.len() - 1;
+                        scanner.states[state].next[byte as usize] = new_state;
+                        new_state
+                    }
+                    next => next,
+                };
+            }
+
+            scanner.states[state].outputs.push(scanner.patterns.len());
+            scanner.patterns.push((pattern.len(), value));
+        }
+
+        scanner.link_failures();
+
+        scanner
+    }
+
+    /// Turns the trie into a complete automaton, breadth first so that the
+    /// failure state of every state is final before its children need it.
+    fn link_failures(&mut self) {
+        let mut failures = vec![ROOT; self.states.len()];
+        let mut queue: VecDeque<usize> = self.states[ROOT]
+            .next
+            .iter()
+            .copied()
+            .filter(|&child| child != ROOT)
+            .collect();
+
+        while let Some(state) = queue.pop_front() {
+            let failure_outputs = self.states[failures[state]].outputs.clone();
+            self.states[state].outputs.extend(failure_outputs);
+
+            for byte in 0..256 {
+                let child = self.states[state].next[byte];
+                let fallback = self.states[failures[state]].next[byte];
+
+                if child == ROOT {
+                    self.states[state].next[byte] = fallback;
+                } else {
+                    failures[child] = fallback;
+                    queue.push_back(child);
+                }
+            }
+        }
+    }
+
+    /// Every occurrence of every pattern in `text`, ordered by where they end.
+    pub fn find_iter<'a>(&'a self, text: &'a str) -> impl Iterator<Item = Match> + 'a {
+        text.bytes()
+            .enumerate()
+            .scan(ROOT, move |state, (i, byte)| {
+                *state = self.states[*state].next[byte as usize];
+                Some((i + 1, &self.states[*state].outputs))
+            })
+            .flat_map(move |(end, outputs)| {
+                outputs.iter().map(move |&pattern| {
+                    let (len, value) = self.patterns[pattern];
+
+                    Match {
+                        value,
+                        span: end - len..end,
+                    }
+                })
+            })
+    }
+
+    /// The occurrences starting first and last in `text`, which may be the
+    /// same one.
+    pub fn first_and_last(&self, text: &str) -> Option<(Match, Match)> {
+        let mut matches = self.find_iter(text);
+        let first = matches.next()?;
+
+        Some(matches.fold((first.clone(), first), |(first, last), m| {
+            let first = if m.span.start < first.span.start {
+                m.clone()
+            } else {
+                first
+            };
+            let last = if m.span.start > last.span.start {
+                m
+            } else {
+                last
+            };
+
+            (first, last)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(scanner: &Scanner, text: &str) -> Vec<(u32, Range<usize>)> {
+        scanner.find_iter(text).map(|m| (m.value, m.span)).collect()
+    }
+
+    #[test]
+    fn test_find_iter() {
+        let scanner = Scanner::new([("he", 1), ("she", 2), ("his", 3), ("hers", 4)]);
+
+        assert_eq!(
+            values(&scanner, "ushers"),
+            vec![(2, 1..4), (1, 2..4), (4, 2..6)]
+        );
+        assert_eq!(
+            values(&scanner, "ahishe"),
+            vec![(3, 1..4), (2, 3..6), (1, 4..6)]
+        );
+        assert_eq!(values(&scanner, "xyz"), vec![]);
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let scanner = Scanner::new([("one", 1), ("two", 2), ("eight", 8), ("1", 1)]);
+
+        assert_eq!(values(&scanner, "eightwo"), vec![(8, 0..5), (2, 4..7)]);
+        assert_eq!(
+            values(&scanner, "oneight1"),
+            vec![(1, 0..3), (8, 2..7), (1, 7..8)]
+        );
+    }
+
+    #[test]
+    fn test_first_and_last() {
+        let scanner = Scanner::new([("one", 1), ("eight", 8)]);
+
+        let (first, last) = scanner.first_and_last("xoneightx").unwrap();
+        assert_eq!((first.value, last.value), (1, 8));
+
+        let (first, last) = scanner.first_and_last("one").unwrap();
+        assert_eq!(first, last);
+
+        assert_eq!(scanner.first_and_last("two"), None);
+    }
+}
@@ -0,0 +1,320 @@
+use std::{fs, io, path::Path};
+
+use aoc_core::{error::lines, ParseError};
+
+use crate::scanner::Scanner;
+
+/// The words recognized as digits when recovering calibration values, e.g.
+/// `"7"`, `"seven"` or `"sept"`, each with the digit it stands for.
+///
+/// Besides the builder methods, a vocabulary can be read from a file of
+/// `word = digit` lines, where `use <preset>` pulls in a whole preset:
+///
+/// ```text
+/// # French, with zero
+/// use digits
+/// use french
+/// zéro = 0
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+}
+
+pub const PRESETS: [&str; 5] = ["digits", "unicode", "english", "french", "german"];
+
+/// The zero of every set of decimal digits in Unicode 14 (general category
+/// `Nd`), each set being ten consecutive code points from 0 to 9: ASCII,
+/// Arabic-Indic, Devanagari, full-width...
+const DECIMAL_ZEROS: [u32; 66] = [
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66, 0x0CE6,
+    0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80,
+    0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0,
+    0xFF10, 0x104A0, 0x10D30, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0,
+    0x11650, 0x116C0, 0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x16A60, 0x16AC0,
+    0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E950, 0x1FBF0,
+];
+
+/// The value of `c` if it is a decimal digit of any script, e.g. 3 for `'٣'`
+/// (Arabic-Indic) or `'３'` (full-width).
+pub fn decimal_value(c: char) -> Option<u32> {
+    let code = c as u32;
+
+    DECIMAL_ZEROS
+        .iter()
+        .find(|&&zero| (zero..zero + 10).contains(&code))
+        .map(|zero| code - zero)
+}
+
+impl Vocabulary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The numeric digits `1` to `9`.
+    pub fn digits() -> Self {
+        Self::from_words(&["1", "2", "3", "4", "5", "6", "7", "8", "9"])
+    }
+
+    /// The decimal digits `1` to `9` of every script, such as `٣`, `３` or
+    /// `३`, ASCII included.
+    pub fn unicode_digits() -> Self {
+        DECIMAL_ZEROS
+            .iter()
+            .flat_map(|zero| (1..10).map(move |value| (zero + value, value)))
+            .fold(Self::new(), |vocabulary, (code, value)| {
+                let digit = char::from_u32(code).expect("Digits are valid code points");
+
+                vocabulary.word(digit.encode_utf8(&mut [0; 4]), value)
+            })
+    }
+
+    pub fn english() -> Self {
+        Self::from_words(&[
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ])
+    }
+
+    pub fn french() -> Self {
+        Self::from_words(&[
+            "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+        ])
+    }
+
+    pub fn german() -> Self {
+        Self::from_words(&[
+            "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+        ])
+    }
+
+    pub fn preset(name: &str) -> Option<Self> {
+        match name {
+            "digits" => Some(Self::digits()),
+            "unicode" => Some(Self::unicode_digits()),
+            "english" => Some(Self::english()),
+            "french" => Some(Self::french()),
+            "german" => Some(Self::german()),
+            _ => None,
+        }
+    }
+
+    /// `words[i]` standing for `i + 1`.
+    fn from_words(words: &[&str]) -> Self {
+        words
+            .iter()
+            .zip(1..)
+            .fold(Self::new(), |vocabulary, (word, value)| {
+                vocabulary.word(word, value)
+            })
+    }
+
+    /// Adds `word` standing for `digit`, replacing its previous digit if it
+    /// was already known.
+    pub fn word(mut self, word: &str, digit: u32) -> Self {
+        assert!(!word.is_empty(), "A digit word cannot be empty");
+        assert!(digit <= 9, "'{}' must stand for a single digit", word);
+
+        match self.words.iter_mut().find(|(known, _)| known == word) {
+            Some((_, value)) => *value = digit,
+            None => self.words.push((word.to_string(), digit)),
+        }
+
+        self
+    }
+
+    /// Adds every word of `other`.
+    pub fn with(self, other: Vocabulary) -> Self {
+        other.words.iter().fold(self, |vocabulary, (word, digit)| {
+            vocabulary.word(word, *digit)
+        })
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words
+            .iter()
+            .map(|(word, digit)| (word.as_str(), *digit))
+    }
+
+    pub fn scanner(&self) -> Scanner {
+        Scanner::new(self.words())
+    }
+
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut vocabulary = Self::new();
+
+        for line in lines(text) {
+            let content = line.text.trim();
+
+            if content.is_empty() || content.starts_with('#') {
+                continue;
+            }
+
+            if let Some(name) = content.strip_prefix("use ") {
+                let name = name.trim();
+                let preset = Self::preset(name).ok_or_else(|| {
+                    line.error(
+                        name,
+                        "a preset: 'digits', 'unicode', 'english', 'french' or 'german'",
+                    )
+                })?;
+
+                vocabulary = vocabulary.with(preset);
+                continue;
+            }
+
+            let (word, digit) = line.split_once(content, "=")?;
+            let (word, digit) = (word.trim(), digit.trim());
+
+            if word.is_empty() {
+                return Err(line.error(content, "a word before '='"));
+            }
+
+            let value: u32 = line.number(digit)?;
+
+            if value > 9 {
+                return Err(line.error(digit, "a digit from 0 to 9"));
+            }
+
+            if vocabulary.words().any(|(known, _)| known == word) {
+                return Err(line.error(word, "a word that is not defined yet"));
+            }
+
+            vocabulary = vocabulary.word(word, value);
+        }
+
+        Ok(vocabulary)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Result<Self, ParseError>> {
+        Ok(Self::parse(&fs::read_to_string(path)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calibration_value;
+
+    fn value(vocabulary: &Vocabulary, line: &str) -> Option<u32> {
+        calibration_value(&vocabulary.scanner(), line)
+    }
+
+    #[test]
+    fn test_presets() {
+        for name in PRESETS {
+            let count = Vocabulary::preset(name).unwrap().words().count();
+
+            if name == "unicode" {
+                assert_eq!(count, DECIMAL_ZEROS.len() * 9);
+            } else {
+                assert_eq!(count, 9);
+            }
+        }
+
+        assert_eq!(Vocabulary::preset("klingon"), None);
+    }
+
+    #[test]
+    fn test_decimal_value() {
+        assert_eq!(decimal_value('7'), Some(7));
+        assert_eq!(decimal_value('٣'), Some(3));
+        assert_eq!(decimal_value('３'), Some(3));
+        assert_eq!(decimal_value('९'), Some(9));
+        assert_eq!(decimal_value('𝟘'), Some(0));
+        assert_eq!(decimal_value('x'), None);
+        assert_eq!(decimal_value('Ⅻ'), None);
+    }
+
+    #[test]
+    fn test_unicode_digits() {
+        let vocabulary = Vocabulary::unicode_digits().with(Vocabulary::english());
+
+        assert_eq!(value(&vocabulary, "٣abc٧"), Some(37));
+        assert_eq!(value(&vocabulary, "é５x"), Some(55));
+        assert_eq!(value(&vocabulary, "२oneight"), Some(28));
+        // Every digit stands for its value, not its code point.
+        assert!(Vocabulary::unicode_digits()
+            .words()
+            .all(|(word, value)| word.chars().map(decimal_value).eq([Some(value)])));
+    }
+
+    #[test]
+    fn test_english_overlaps() {
+        let vocabulary = Vocabulary::english().word("zero", 0);
+
+        assert_eq!(value(&vocabulary, "oneight"), Some(18));
+        assert_eq!(value(&vocabulary, "xtwone"), Some(21));
+        assert_eq!(value(&vocabulary, "zerone"), Some(1));
+        assert_eq!(value(&vocabulary, "eightwozero"), Some(80));
+    }
+
+    #[test]
+    fn test_french_overlaps() {
+        let vocabulary = Vocabulary::digits().with(Vocabulary::french());
+
+        assert_eq!(value(&vocabulary, "septrois"), Some(73));
+        assert_eq!(value(&vocabulary, "huitrois"), Some(83));
+        assert_eq!(value(&vocabulary, "4deuxneuf"), Some(49));
+        // "quatre" ends in "tre", which is not "trois".
+        assert_eq!(value(&vocabulary, "quatrex"), Some(44));
+    }
+
+    #[test]
+    fn test_german_overlaps() {
+        let vocabulary = Vocabulary::german();
+
+        assert_eq!(value(&vocabulary, "zweins"), Some(21));
+        assert_eq!(value(&vocabulary, "einsieben"), Some(17));
+        assert_eq!(value(&vocabulary, "fünfacht"), Some(58));
+    }
+
+    #[test]
+    fn test_word_replaces_digit() {
+        let vocabulary = Vocabulary::english().word("one", 7);
+
+        assert_eq!(vocabulary.words().count(), 9);
+        assert_eq!(value(&vocabulary, "one"), Some(77));
+    }
+
+    #[test]
+    fn test_parse() {
+        let vocabulary = Vocabulary::parse(
+            "# French, with zero
+use digits
+use french
+
+zéro = 0
+",
+        )
+        .unwrap();
+
+        assert_eq!(
+            vocabulary,
+            Vocabulary::digits()
+                .with(Vocabulary::french())
+                .word("zéro", 0)
+        );
+        assert_eq!(value(&vocabulary, "zéroun"), Some(1));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Vocabulary::parse("use klingon").unwrap_err();
+        assert_eq!((error.line, error.span), (1, 4..11));
+
+        let error = Vocabulary::parse("un = 1\ndeux = 12").unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (2, "a digit from 0 to 9")
+        );
+
+        let error = Vocabulary::parse("un = one").unwrap_err();
+        assert_eq!(error.expected, "a number");
+
+        let error = Vocabulary::parse("un 1").unwrap_err();
+        assert_eq!(error.expected, "'='");
+
+        let error = Vocabulary::parse("un = 1\nun = 2").unwrap_err();
+        assert_eq!((error.line, error.span), (2, 0..2));
+    }
+}
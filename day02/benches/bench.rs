@@ -0,0 +1,3 @@
+fn main() {
+    aoc_core::bench::main::<day02::Day02>(env!("CARGO_MANIFEST_DIR"));
+}
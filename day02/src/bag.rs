@@ -0,0 +1,123 @@
+//! Which games could have been played with a given bag, and why the others
+//! could not.
+
+use std::{collections::BTreeSet, fmt};
+
+use aoc_core::{Line, ParseError};
+
+use crate::{cubes::Cubes, parse_cubes, Game};
+
+/// Parses a bag written like a grab, e.g. `12 red, 13 green, 14 blue`.
+pub fn parse_bag(text: &str) -> Result<Cubes, ParseError> {
+    let line = Line::new(1, text.trim());
+
+    parse_cubes(&line, line.text)
+}
+
+/// A grab showing more cubes of a colour than the bag holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub game: u32,
+    /// 1-based index of the grab in the game.
+    pub draw: usize,
+    pub colour: String,
+    pub drawn: u32,
+    pub limit: u32,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Game {}: draw {} shows {} {}, the bag holds {}",
+            self.game, self.draw, self.drawn, self.colour, self.limit
+        )
+    }
+}
+
+impl Game {
+    /// Every colour of every grab exceeding `bag`, none for a possible game.
+    pub fn violations(&self, bag: &Cubes) -> Vec<Violation> {
+        self.sets()
+            .iter()
+            .zip(1..)
+            .flat_map(|(set, draw)| {
+                set.iter()
+                    .filter(|&(colour, drawn)| drawn > bag.get(colour))
+                    .map(move |(colour, drawn)| Violation {
+                        game: self.id(),
+                        draw,
+                        colour: colour.to_string(),
+                        drawn,
+                        limit: bag.get(colour),
+                    })
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Feasibility {
+    /// Ids of the games possible with the bag.
+    pub feasible: BTreeSet<u32>,
+    /// Why the other games are not, in game order.
+    pub violations: Vec<Violation>,
+}
+
+pub fn feasibility(games: &[Game], bag: &Cubes) -> Feasibility {
+    let mut feasibility = Feasibility::default();
+
+    for game in games {
+        let violations = game.violations(bag);
+
+        if violations.is_empty() {
+            feasibility.feasible.insert(game.id());
+        } else {
+            feasibility.violations.extend(violations);
+        }
+    }
+
+    feasibility
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day02, PART1_BAG};
+    use aoc_core::{examples, Solution};
+
+    #[test]
+    fn test_parse_bag() {
+        assert_eq!(
+            parse_bag(PART1_BAG).unwrap(),
+            Cubes::new()
+                .with("red", 12)
+                .with("green", 13)
+                .with("blue", 14)
+        );
+
+        let error = parse_bag("12 red, lots green").unwrap_err();
+        assert_eq!((error.span, error.expected.as_str()), (8..12, "a number"));
+    }
+
+    #[test]
+    fn test_feasibility() {
+        let input = examples::input(env!("CARGO_MANIFEST_DIR"), "example.txt");
+        let games = Day02::parse(&input).unwrap();
+        let feasibility = feasibility(&games, &parse_bag(PART1_BAG).unwrap());
+
+        assert_eq!(feasibility.feasible, BTreeSet::from([1, 2, 5]));
+        assert_eq!(feasibility.violations.len(), 3);
+        assert_eq!(
+            feasibility.violations[0].to_string(),
+            "Game 3: draw 1 shows 20 red, the bag holds 12"
+        );
+        assert_eq!(
+            (
+                feasibility.violations[2].game,
+                feasibility.violations[2].draw
+            ),
+            (4, 3)
+        );
+    }
+}
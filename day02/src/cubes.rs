@@ -0,0 +1,121 @@
+use std::{collections::BTreeMap, fmt};
+
+/// A number of cubes of each colour, whatever the colours are: the cubes
+/// shown in one grab, or those in a bag. Colours without cubes count as 0.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cubes {
+    counts: BTreeMap<String, u32>,
+}
+
+impl Cubes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, colour: &str) -> u32 {
+        self.counts.get(colour).copied().unwrap_or(0)
+    }
+
+    /// Adds `count` cubes of `colour`.
+    pub fn add(&mut self, colour: &str, count: u32) {
+        *self.counts.entry(colour.to_string()).or_insert(0) += count;
+    }
+
+    /// Builder flavour of `add`.
+    pub fn with(mut self, colour: &str, count: u32) -> Self {
+        self.add(colour, count);
+        self
+    }
+
+    /// The colours mentioned, in alphabetical order, with their counts.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.counts
+            .iter()
+            .map(|(colour, count)| (colour.as_str(), *count))
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.counts.keys().map(String::as_str)
+    }
+
+    /// Whether every one of these cubes could have come out of `bag`.
+    pub fn fits_in(&self, bag: &Cubes) -> bool {
+        self.iter().all(|(colour, count)| count <= bag.get(colour))
+    }
+
+    /// The fewest cubes of each colour holding both `self` and `other`.
+    pub fn union(&self, other: &Cubes) -> Cubes {
+        let mut union = self.clone();
+
+        for (colour, count) in other.iter() {
+            let entry = union.counts.entry(colour.to_string()).or_insert(0);
+            *entry = (*entry).max(count);
+        }
+
+        union
+    }
+
+    /// The product of the counts of `colours`, a missing colour making it 0,
+    /// or `None` if it overflows a `u64`.
+    pub fn power<'a>(&self, colours: impl IntoIterator<Item = &'a str>) -> Option<u64> {
+        colours.into_iter().try_fold(1u64, |power, colour| {
+            power.checked_mul(self.get(colour) as u64)
+        })
+    }
+}
+
+impl fmt::Display for Cubes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (colour, count)) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{} {}", count, colour)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fits_in() {
+        let bag = Cubes::new().with("red", 12).with("green", 13);
+
+        assert!(Cubes::new().with("red", 12).fits_in(&bag));
+        assert!(!Cubes::new().with("red", 13).fits_in(&bag));
+        assert!(!Cubes::new().with("purple", 1).fits_in(&bag));
+        assert!(Cubes::new().with("purple", 0).fits_in(&bag));
+    }
+
+    #[test]
+    fn test_union_and_power() {
+        let a = Cubes::new().with("red", 4).with("blue", 3);
+        let b = Cubes::new().with("red", 1).with("green", 2).with("blue", 6);
+        let union = a.union(&b);
+
+        assert_eq!(union.to_string(), "6 blue, 2 green, 4 red");
+        assert_eq!(union.power(union.colours()), Some(48));
+        assert_eq!(union.power(["red", "purple"]), Some(0));
+
+        let big = Cubes::new().with("red", u32::MAX).with("blue", u32::MAX);
+        assert_eq!(
+            big.power(["red", "blue"]),
+            Some(u32::MAX as u64 * u32::MAX as u64)
+        );
+        assert_eq!(big.with("green", 2).power(["red", "blue", "green"]), None);
+    }
+
+    #[test]
+    fn test_add() {
+        let mut cubes = Cubes::new().with("red", 1);
+        cubes.add("red", 2);
+
+        assert_eq!(cubes.get("red"), 3);
+        assert_eq!(cubes.get("blue"), 0);
+    }
+}
@@ -0,0 +1,203 @@
+pub mod bag;
+pub mod cubes;
+pub mod stats;
+
+use aoc_core::{error::lines, Line, ParseError, Solution};
+use cubes::Cubes;
+use std::{collections::BTreeSet, fmt::Display};
+
+/// The cubes shown in one grab.
+pub type GrabSet = Cubes;
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    sets: Vec<GrabSet>,
+}
+
+impl Game {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn sets(&self) -> &[GrabSet] {
+        &self.sets
+    }
+
+    /// Whether every grab of the game could have come out of `bag`.
+    pub fn is_possible(&self, bag: &Cubes) -> bool {
+        self.sets.iter().all(|set| set.fits_in(bag))
+    }
+
+    /// The fewest cubes of each colour the bag must have held.
+    pub fn minimum_bag(&self) -> Cubes {
+        self.sets
+            .iter()
+            .fold(Cubes::new(), |bag, set| bag.union(set))
+    }
+}
+
+/// Every colour shown in any game.
+pub fn colours(games: &[Game]) -> BTreeSet<&str> {
+    games
+        .iter()
+        .flat_map(|game| game.sets.iter().flat_map(|set| set.colours()))
+        .collect()
+}
+
+/// Parses cubes written as in a grab, e.g. `3 blue, 4 red`. `text` must be
+/// a slice of `line`.
+pub(crate) fn parse_cubes<'a>(line: &Line<'a>, text: &'a str) -> Result<Cubes, ParseError> {
+    let mut cubes = Cubes::new();
+
+    for color_info in text.split(", ") {
+        let (amount, color) = line.split_once(color_info, " ")?;
+        let amount = line.number::<u32>(amount)?;
+
+        if color.is_empty() {
+            return Err(line.error_after(color_info, "a colour"));
+        }
+
+        if cubes.get(color).checked_add(amount).is_none() {
+            return Err(line.error(color_info, "cubes of one colour that fit in a u32"));
+        }
+
+        cubes.add(color, amount);
+    }
+
+    Ok(cubes)
+}
+
+fn parse_line(line: Line) -> Result<Game, ParseError> {
+    let (game_info, sets_info) = line.split_once(line.text, ": ")?;
+
+    let game_id: u32 = line.number(line.strip_prefix(game_info, "Game ")?)?;
+
+    let sets: Vec<GrabSet> = sets_info
+        .split("; ")
+        .map(|set| parse_cubes(&line, set))
+        .collect::<Result<Vec<GrabSet>, ParseError>>()?;
+
+    let game: Game = Game { id: game_id, sets };
+
+    Ok(game)
+}
+
+/// The bag of part 1.
+pub const PART1_BAG: &str = "12 red, 13 green, 14 blue";
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Game>;
+
+    /// Also rejects the game from which the total power would overflow a
+    /// `u64`, so that part 2 cannot fail.
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let lines: Vec<Line> = lines(input).collect();
+        let games: Vec<Game> = lines
+            .iter()
+            .map(|&line| parse_line(line))
+            .collect::<Result<_, _>>()?;
+
+        let colours = colours(&games);
+        let mut total = 0u64;
+
+        for (line, game) in lines.iter().zip(&games) {
+            total = game
+                .minimum_bag()
+                .power(colours.iter().copied())
+                .and_then(|power| total.checked_add(power))
+                .ok_or_else(|| line.error(line.text, "a game whose power fits in a u64"))?;
+        }
+
+        Ok(games)
+    }
+
+    fn part1(games: &Self::Input<'_>) -> impl Display {
+        let bag = bag::parse_bag(PART1_BAG).expect("The part 1 bag is valid");
+
+        bag::feasibility(games, &bag)
+            .feasible
+            .iter()
+            .map(|&id| id as u64)
+            .sum::<u64>()
+    }
+
+    /// A colour seen in other games but never in this one makes its power 0.
+    fn part2(games: &Self::Input<'_>) -> impl Display {
+        let colours = colours(games);
+
+        games
+            .iter()
+            .map(|game| {
+                game.minimum_bag()
+                    .power(colours.iter().copied())
+                    .expect("checked while parsing")
+            })
+            .sum::<u64>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::example_tests!(Day02);
+
+    #[test]
+    fn test_parse_line() {
+        let input = "Game 24: 1 green, 2 blue; 13 red, 2 blue, 3 green; 4 green, 14 red";
+        let game = parse_line(Line::new(1, input)).unwrap();
+
+        assert_eq!(game.id, 24);
+        assert_eq!(game.sets.len(), 3);
+        assert_eq!(game.sets[1].get("red"), 13);
+        assert_eq!(game.minimum_bag().to_string(), "2 blue, 4 green, 14 red");
+    }
+
+    #[test]
+    fn test_any_colour() {
+        let game = parse_line(Line::new(1, "Game 1: 1 green, 2 purple; 3 purple")).unwrap();
+        let bag = Cubes::new().with("green", 1).with("purple", 2);
+
+        assert!(!game.is_possible(&bag));
+        assert!(game.is_possible(&bag.with("purple", 1)));
+        assert_eq!(game.minimum_bag().power(["green", "purple"]), Some(3));
+        assert_eq!(colours(&[game]), BTreeSet::from(["green", "purple"]));
+    }
+
+    #[test]
+    fn test_parse_line_errors() {
+        let error = parse_line(Line::new(3, "Game x: 1 green")).unwrap_err();
+        assert_eq!(
+            (error.line, error.span, error.expected.as_str()),
+            (3, 5..6, "a number")
+        );
+
+        let error = parse_line(Line::new(1, "Game 1: 1 green, 2 ")).unwrap_err();
+        assert_eq!((error.span, error.expected.as_str()), (19..19, "a colour"));
+
+        let error = parse_line(Line::new(1, "Game 1: 1 green, 2")).unwrap_err();
+        assert_eq!(error.span, 18..18);
+
+        assert!(parse_line(Line::new(1, "Game 1")).is_err());
+
+        let error = parse_line(Line::new(1, "Game 1: 4294967295 red, 1 red")).unwrap_err();
+        assert_eq!(error.span, 24..29);
+    }
+
+    #[test]
+    fn test_power_overflow() {
+        let input = "Game 1: 1 red, 1 blue, 1 green\n\
+                     Game 2: 4294967295 red, 4294967295 blue, 2 green\n";
+        let error = Day02::parse(input).unwrap_err();
+
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (2, "a game whose power fits in a u64")
+        );
+    }
+}
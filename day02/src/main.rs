@@ -1,110 +1,84 @@
-use std::fs;
+use std::{env, path::Path, process};
 
-#[derive(Debug)]
-struct GrabSet {
-    red: u32,
-    green: u32,
-    blue: u32,
-}
+use aoc_core::{input::InputSource, Solution};
+use day02::{bag, stats::Stats, Day02};
+
+const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+const BAG_USAGE: &str = "Usage: day02 --bag <BAG> [<INPUT>|-]";
+
+const STATS_USAGE: &str = "Usage: day02 --stats <json|csv|histogram-csv> [<INPUT>|-]";
+
+fn read_games(input: Option<&Path>) -> Result<Vec<day02::Game>, String> {
+    let source = InputSource::from_env(Day02::DAY, input, DEFAULT_INPUT);
+    let text = source
+        .read()
+        .map_err(|error| format!("Could not read {}: {}", source, error))?;
 
-#[derive(Debug)]
-struct Game {
-    id: u32,
-    sets: Vec<GrabSet>,
+    Day02::parse(&text).map_err(|error| error.to_string())
 }
 
-fn parse_line(line: &str) -> Game {
-    let parts: Vec<&str> = line.split(": ").collect();
-    let (game_info, sets_info) = (parts[0], parts[1]);
-
-    let game_id: u32 = game_info
-        .chars()
-        .filter(|c| c.is_digit(10))
-        .collect::<String>()
-        .parse::<u32>()
-        .unwrap();
-
-    let sets: Vec<GrabSet> = sets_info
-        .split("; ")
-        .map(|set: &str| {
-            let colors_info = set.split(", ");
-
-            let mut grab_set: GrabSet = GrabSet {
-                red: 0,
-                green: 0,
-                blue: 0,
-            };
-
-            colors_info.for_each(|color_info| {
-                let color_parts: Vec<&str> = color_info.split(" ").collect();
-                let (amount, color) = (color_parts[0].parse::<u32>().unwrap(), color_parts[1]);
-
-                match color {
-                    "red" => grab_set.red = amount,
-                    "blue" => grab_set.blue = amount,
-                    "green" => grab_set.green = amount,
-                    _ => {
-                        println!("Unknown color: {}", color)
-                    }
-                }
-            });
-
-            grab_set
-        })
-        .collect();
-
-    let game: Game = Game {
-        id: game_id,
-        sets: sets,
+/// Prints the games possible with the bag given on the command line, and
+/// every draw making the others impossible.
+fn run_bag(args: &[String]) -> Result<(), String> {
+    let (bag, input) = match args {
+        [bag] => (bag, None),
+        [bag, input] => (bag, Some(Path::new(input))),
+        _ => return Err(format!("--bag expects a bag\n\n{}", BAG_USAGE)),
     };
 
-    return game;
+    let bag = bag::parse_bag(bag).map_err(|error| error.to_string())?;
+
+    let games = read_games(input)?;
+    let feasibility = bag::feasibility(&games, &bag);
+    let ids: Vec<String> = feasibility.feasible.iter().map(u32::to_string).collect();
+
+    println!(
+        "Feasible games: {} (sum {})",
+        ids.join(", "),
+        feasibility.feasible.iter().sum::<u32>()
+    );
+
+    for violation in feasibility.violations {
+        println!("{}", violation);
+    }
+
+    Ok(())
 }
 
-fn main() {
-    let input = fs::read_to_string("./input.txt").unwrap();
-
-    let games = input.lines().map(|line| parse_line(line));
-
-    const MIN_RED: u32 = 12;
-    const MIN_GREEN: u32 = 13;
-    const MIN_BLUE: u32 = 14;
-
-    let answer1 = games
-        .clone()
-        .filter(|game| {
-            game.sets
-                .iter()
-                .all(|set| set.red <= MIN_RED && set.green <= MIN_GREEN && set.blue <= MIN_BLUE)
-        })
-        .fold(0, |acc, game| acc + game.id);
-
-    let answer2 = games
-        .clone()
-        .map(|game| {
-            let red = game.sets.iter().map(|set| set.red).max().unwrap();
-            let green = game.sets.iter().map(|set| set.green).max().unwrap();
-            let blue = game.sets.iter().map(|set| set.blue).max().unwrap();
-
-            red * green * blue
-        })
-        .fold(0, |acc, value| acc + value);
-
-    println!("Answer 1: {}", answer1,);
-    println!("Answer 2: {}", answer2);
+/// Prints the per-game minimum bags and powers, or the per-colour histograms
+/// of the draws.
+fn run_stats(args: &[String]) -> Result<(), String> {
+    let (format, input) = match args {
+        [format] => (format, None),
+        [format, input] => (format, Some(Path::new(input))),
+        _ => return Err(format!("--stats expects a format\n\n{}", STATS_USAGE)),
+    };
+
+    let games = read_games(input)?;
+    let stats = Stats::new(&games);
+
+    match format.as_str() {
+        "json" => println!("{}", stats.to_json()),
+        "csv" => print!("{}", stats.games_csv()),
+        "histogram-csv" => print!("{}", stats.histograms_csv()),
+        _ => return Err(format!("Unknown format '{}'\n\n{}", format, STATS_USAGE)),
+    }
+
+    Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
 
-    #[test]
-    fn test_parse_line() {
-        let input = "Game 24: 1 green, 2 blue; 13 red, 2 blue, 3 green; 4 green, 14 red";
-        let game = parse_line(input);
+    let command = match args.first().map(String::as_str) {
+        Some("--bag") => run_bag,
+        Some("--stats") => run_stats,
+        _ => return aoc_core::run::<Day02>(DEFAULT_INPUT),
+    };
 
-        println!("{:?}", game.sets);
-        assert_eq!(game.id, 24);
-        assert_eq!(game.sets.len(), 3);
+    if let Err(error) = command(&args[1..]) {
+        eprintln!("{}", error);
+        process::exit(2);
     }
 }
@@ -0,0 +1,215 @@
+//! A summary of the games for analysis outside of the puzzle: the minimum
+//! bag and power of every game, and how many cubes of each colour the draws
+//! tend to show.
+
+use std::collections::BTreeMap;
+
+use aoc_core::json;
+
+use crate::{colours, cubes::Cubes, Game};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameStats {
+    pub id: u32,
+    pub draws: usize,
+    pub minimum_bag: Cubes,
+    /// Power of the minimum bag over every colour of the input, `None` if it
+    /// overflows a `u64`.
+    pub power: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stats {
+    /// Every colour of the input, in alphabetical order.
+    pub colours: Vec<String>,
+    pub games: Vec<GameStats>,
+    /// For every colour, how many draws showed each number of its cubes.
+    /// Draws not mentioning a colour are not counted for it.
+    pub histograms: BTreeMap<String, BTreeMap<u32, usize>>,
+}
+
+impl Stats {
+    pub fn new(games: &[Game]) -> Self {
+        let colours: Vec<String> = colours(games).into_iter().map(String::from).collect();
+        let mut histograms: BTreeMap<String, BTreeMap<u32, usize>> = BTreeMap::new();
+
+        for set in games.iter().flat_map(|game| game.sets()) {
+            for (colour, count) in set.iter() {
+                *histograms
+                    .entry(colour.to_string())
+                    .or_default()
+                    .entry(count)
+                    .or_insert(0) += 1;
+            }
+        }
+
+        let games = games
+            .iter()
+            .map(|game| {
+                let minimum_bag = game.minimum_bag();
+
+                GameStats {
+                    id: game.id(),
+                    draws: game.sets().len(),
+                    power: minimum_bag.power(colours.iter().map(String::as_str)),
+                    minimum_bag,
+                }
+            })
+            .collect();
+
+        Self {
+            colours,
+            games,
+            histograms,
+        }
+    }
+
+    /// One row per game: its id, number of draws, minimum count of every
+    /// colour and power.
+    pub fn games_csv(&self) -> String {
+        let mut header = vec!["game".to_string(), "draws".to_string()];
+        header.extend(self.colours.iter().map(|colour| csv_field(colour)));
+        header.push("power".to_string());
+
+        let mut rows = vec![header.join(",")];
+
+        for game in &self.games {
+            let mut row = vec![game.id.to_string(), game.draws.to_string()];
+            row.extend(
+                self.colours
+                    .iter()
+                    .map(|colour| game.minimum_bag.get(colour).to_string()),
+            );
+            row.push(game.power.map_or(String::new(), |power| power.to_string()));
+
+            rows.push(row.join(","));
+        }
+
+        rows.join("\n") + "\n"
+    }
+
+    /// One row per colour and number of cubes shown, with how many draws
+    /// showed it.
+    pub fn histograms_csv(&self) -> String {
+        let mut rows = vec!["colour,cubes,draws".to_string()];
+
+        for (colour, histogram) in &self.histograms {
+            for (cubes, draws) in histogram {
+                rows.push(format!("{},{},{}", csv_field(colour), cubes, draws));
+            }
+        }
+
+        rows.join("\n") + "\n"
+    }
+
+    pub fn to_json(&self) -> String {
+        let colours: Vec<String> = self.colours.iter().map(|c| json::string(c)).collect();
+
+        let games: Vec<String> = self
+            .games
+            .iter()
+            .map(|game| {
+                let minimum_bag: Vec<(&str, String)> = self
+                    .colours
+                    .iter()
+                    .map(|colour| (colour.as_str(), game.minimum_bag.get(colour).to_string()))
+                    .collect();
+
+                json::object(&[
+                    ("id", game.id.to_string()),
+                    ("draws", game.draws.to_string()),
+                    ("minimum_bag", json::object(&minimum_bag)),
+                    (
+                        "power",
+                        game.power
+                            .map_or("null".to_string(), |power| power.to_string()),
+                    ),
+                ])
+            })
+            .collect();
+
+        let histograms: Vec<(&str, String)> = self
+            .histograms
+            .iter()
+            .map(|(colour, histogram)| {
+                let counts: Vec<(String, String)> = histogram
+                    .iter()
+                    .map(|(cubes, draws)| (cubes.to_string(), draws.to_string()))
+                    .collect();
+                let counts: Vec<(&str, String)> = counts
+                    .iter()
+                    .map(|(cubes, draws)| (cubes.as_str(), draws.clone()))
+                    .collect();
+
+                (colour.as_str(), json::object(&counts))
+            })
+            .collect();
+
+        json::object(&[
+            ("colours", json::array(&colours)),
+            ("games", json::array(&games)),
+            ("histograms", json::object(&histograms)),
+        ])
+    }
+}
+
+/// Quotes `value` if it would otherwise break the CSV row.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use aoc_core::Solution;
+
+    fn stats(input: &str) -> Stats {
+        Stats::new(&Day02::parse(input).unwrap())
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats =
+            stats("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\nGame 2: 1 blue\n");
+
+        assert_eq!(stats.colours, ["blue", "green", "red"]);
+        assert_eq!((stats.games[0].draws, stats.games[0].power), (3, Some(48)));
+        assert_eq!(stats.games[1].power, Some(0));
+        assert_eq!(stats.histograms["green"], BTreeMap::from([(2, 2)]));
+        assert_eq!(stats.histograms["blue"].values().sum::<usize>(), 3);
+    }
+
+    #[test]
+    fn test_csv() {
+        let stats = stats("Game 1: 3 blue, 4 red; 1 red\nGame 7: 2 blue\n");
+
+        assert_eq!(
+            stats.games_csv(),
+            "game,draws,blue,red,power\n1,2,3,4,12\n7,1,2,0,0\n"
+        );
+        assert_eq!(
+            stats.histograms_csv(),
+            "colour,cubes,draws\nblue,2,1\nblue,3,1\nred,1,1\nred,4,1\n"
+        );
+        assert_eq!(csv_field("dark, red"), "\"dark, red\"");
+    }
+
+    #[test]
+    fn test_to_json() {
+        let stats = stats("Game 1: 3 blue, 4 red; 1 red\n");
+
+        assert_eq!(
+            stats.to_json(),
+            concat!(
+                r#"{"colours":["blue","red"],"#,
+                r#""games":[{"id":1,"draws":2,"minimum_bag":{"blue":3,"red":4},"power":12}],"#,
+                r#""histograms":{"blue":{"3":1},"red":{"1":1,"4":1}}}"#
+            )
+        );
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_core::bench::main::<day03::Day03>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,203 @@
+//! Compares the byte-grid scan of the schematic with the scan it replaced,
+//! on generated schematics much larger than the puzzle's.
+//!
+//! `cargo bench -p day03 --bench scan`
+
+use aoc_core::{bench::measure, Solution};
+use day03::{numbers, Day03, PartNumber};
+
+const SYMBOLS: &[u8] = b"*#+$/=%@&-";
+
+/// A schematic of `width` by `height` cells, the same for a given `seed`.
+fn generate(width: usize, height: usize, seed: u64) -> String {
+    let mut state = seed;
+    let mut next = move || {
+        // Knuth's MMIX linear congruential generator.
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 33) as usize
+    };
+
+    let mut text = String::with_capacity((width + 1) * height);
+
+    for _ in 0..height {
+        let mut digits = 0;
+
+        for _ in 0..width {
+            let c = match next() % 10 {
+                // Keep numbers short, like the puzzle's.
+                6..=8 if digits == 5 => b'.',
+                0..=5 => b'.',
+                6..=8 => b'0' + (next() % 10) as u8,
+                _ => SYMBOLS[next() % SYMBOLS.len()],
+            };
+            digits = if c.is_ascii_digit() { digits + 1 } else { 0 };
+
+            text.push(c as char);
+        }
+
+        text.push('\n');
+    }
+
+    text
+}
+
+/// The scan the byte grid replaced, as it was: rows of `String`s indexed
+/// with `chars().nth()` for every neighbour, quadratic in the row length. It
+/// only counted numbers next to a `*`, so its answers differ, but it did
+/// the same amount of work.
+mod original {
+    use std::collections::{HashMap, HashSet};
+
+    type Grid = Vec<String>;
+
+    fn is_special_cell(grid: &Grid, col: i32, row: i32) -> bool {
+        let width = grid[0].len() as i32;
+        let height = grid.len() as i32;
+
+        if col >= width || row >= height || col < 0 || row < 0 {
+            return false;
+        }
+
+        let character = grid[row as usize].chars().nth(col as usize).unwrap();
+
+        !character.is_ascii_digit() && character != '.'
+    }
+
+    fn is_star(grid: &Grid, col: i32, row: i32) -> bool {
+        let width = grid[0].len() as i32;
+        let height = grid.len() as i32;
+
+        if col >= width || row >= height || col < 0 || row < 0 {
+            return false;
+        }
+
+        grid[row as usize].chars().nth(col as usize).unwrap() == '*'
+    }
+
+    fn visit_line(grid: &Grid, line_index: u32, store: &mut HashMap<String, Vec<i32>>) -> i32 {
+        let line = &grid[line_index as usize];
+        let mut sum = 0;
+        let mut current_number = String::new();
+        let mut is_current_part_number = false;
+        let line_iter = line.chars();
+        let mut special_cells_buffer: HashSet<(i32, i32)> = HashSet::new();
+
+        for (i, c) in line_iter.clone().enumerate() {
+            let previous_col = i as i32 - 1;
+            let next_col = i as i32 + 1;
+            let next_row = line_index as i32 + 1;
+            let previous_row = line_index as i32 - 1;
+
+            let is_next_col_number = match line_iter.clone().nth(i + 1) {
+                Some(val) => val.is_ascii_digit(),
+                None => false,
+            };
+
+            if c.is_ascii_digit() {
+                current_number.push(c);
+
+                let cells_to_check = [
+                    (previous_col, line_index as i32),
+                    (i as i32, previous_row),
+                    (i as i32, next_row),
+                    (previous_col, next_row),
+                    (previous_col, previous_row),
+                    (next_col, line_index as i32),
+                    (next_col, next_row),
+                    (next_col, previous_row),
+                ];
+
+                for (col, row) in cells_to_check {
+                    let is_cell_special =
+                        is_special_cell(grid, col, row) && is_star(grid, col, row);
+
+                    if is_cell_special {
+                        special_cells_buffer.insert((col, row));
+                    }
+
+                    is_current_part_number = is_current_part_number || is_cell_special;
+                }
+            } else if c != '*' {
+                is_current_part_number = false;
+                current_number.clear();
+            } else {
+                is_current_part_number = true;
+            }
+
+            if !is_next_col_number && is_current_part_number {
+                if let Ok(parsed_current_number) = current_number.parse::<i32>() {
+                    for (col, row) in special_cells_buffer.clone() {
+                        store
+                            .entry(format!("{}:{}", col, row))
+                            .or_default()
+                            .push(parsed_current_number);
+                    }
+                    sum += parsed_current_number;
+                }
+
+                special_cells_buffer.clear();
+                current_number.clear();
+                is_current_part_number = false;
+            }
+        }
+
+        sum
+    }
+
+    pub fn visit_grid(input: &str) -> (i32, HashMap<String, Vec<i32>>) {
+        let grid: Grid = input.lines().map(String::from).collect();
+        let mut store = HashMap::new();
+
+        let sum = (0..grid.len()).fold(0, |sum, line_index| {
+            sum + visit_line(&grid, line_index as u32, &mut store)
+        });
+
+        (sum, store)
+    }
+}
+
+fn byte_grid(input: &str) -> Vec<PartNumber> {
+    numbers(&Day03::parse(input).unwrap()).unwrap()
+}
+
+fn main() {
+    // Only run under `cargo bench`, which passes `--bench`.
+    if !std::env::args().any(|arg| arg == "--bench") {
+        return;
+    }
+
+    println!(
+        "{:<12} {:>16} {:>16} {:>10}",
+        "Schematic", "Original", "Byte grid", "Speedup"
+    );
+
+    // Rows so long that the original would take minutes come last.
+    for (width, height) in [(140, 140), (1_000, 100), (10, 100_000), (100_000, 10)] {
+        let input = generate(width, height, 2023);
+
+        let byte_grid = measure(|| {
+            byte_grid(&input);
+        });
+
+        let (original, speedup) = if width <= 1_000 {
+            let original = measure(|| {
+                original::visit_grid(&input);
+            });
+            let speedup = original.mean.as_secs_f64() / byte_grid.mean.as_secs_f64();
+
+            (format!("{:?}", original.mean), format!("{:.1}x", speedup))
+        } else {
+            ("-".to_string(), "-".to_string())
+        };
+
+        println!(
+            "{:<12} {:>16} {:>16?} {:>10}",
+            format!("{}x{}", width, height),
+            original,
+            byte_grid.mean,
+            speedup
+        );
+    }
+}
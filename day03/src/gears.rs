@@ -0,0 +1,199 @@
+//! Which symbols count as gears and how their ratio is computed, the puzzle
+//! rule being a `*` touching exactly two part numbers, multiplied together.
+
+use std::{fmt, ops::RangeInclusive};
+
+use aoc_core::{Line, ParseError};
+
+use crate::{numbers_by_symbol, PartNumber};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combine {
+    Product,
+    Sum,
+}
+
+impl Combine {
+    pub fn apply(self, values: &[u32]) -> u64 {
+        let values = values.iter().map(|&value| value as u64);
+
+        match self {
+            Combine::Product => values.product(),
+            Combine::Sum => values.sum(),
+        }
+    }
+}
+
+/// What makes a symbol a gear, written `<symbols>:<parts>:<combine>` on the
+/// command line, e.g. `*:2:product` for the puzzle, or `*#:2-3:sum`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GearRule {
+    /// The symbols that can be gears.
+    pub symbols: Vec<char>,
+    /// How many part numbers a gear touches.
+    pub parts: RangeInclusive<usize>,
+    /// How the part numbers make up the ratio.
+    pub combine: Combine,
+}
+
+impl Default for GearRule {
+    fn default() -> Self {
+        Self {
+            symbols: vec!['*'],
+            parts: 2..=2,
+            combine: Combine::Product,
+        }
+    }
+}
+
+/// A symbol matching a rule, with the part numbers around it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gear {
+    pub symbol: char,
+    pub row: usize,
+    pub col: usize,
+    pub parts: Vec<u32>,
+    pub ratio: u64,
+}
+
+impl fmt::Display for Gear {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<String> = self.parts.iter().map(u32::to_string).collect();
+
+        write!(
+            f,
+            "'{}' at {}:{} touches {} -> {}",
+            self.symbol,
+            self.row + 1,
+            self.col + 1,
+            parts.join(", "),
+            self.ratio
+        )
+    }
+}
+
+impl GearRule {
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let line = Line::new(1, text.trim());
+
+        let (symbols, rest) = line.split_once(line.text, ":")?;
+        let (parts, combine) = line.split_once(rest, ":")?;
+
+        if symbols.is_empty() {
+            return Err(line.error_after(symbols, "gear symbols"));
+        }
+
+        let parts = match parts.split_once('-') {
+            Some((min, max)) => {
+                let (min, max): (usize, usize) = (line.number(min)?, line.number(max)?);
+
+                if min > max {
+                    return Err(line.error(parts, format!("a range ending at {} or more", min)));
+                }
+
+                min..=max
+            }
+            None => {
+                let parts = line.number(parts)?;
+                parts..=parts
+            }
+        };
+
+        let combine = match combine {
+            "product" => Combine::Product,
+            "sum" => Combine::Sum,
+            _ => return Err(line.error(combine, "'product' or 'sum'")),
+        };
+
+        Ok(Self {
+            symbols: symbols.chars().collect(),
+            parts,
+            combine,
+        })
+    }
+
+    /// The gears among the symbols touching `numbers`, in row-major order.
+    pub fn gears(&self, numbers: &[PartNumber]) -> Vec<Gear> {
+        numbers_by_symbol(numbers)
+            .into_iter()
+            .filter(|(_, (symbol, parts))| {
+                self.symbols.contains(symbol) && self.parts.contains(&parts.len())
+            })
+            .map(|((row, col), (symbol, parts))| Gear {
+                symbol,
+                row,
+                col,
+                ratio: self.combine.apply(&parts),
+                parts,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{numbers, Day03};
+    use aoc_core::{examples, Solution};
+
+    fn example_numbers() -> Vec<PartNumber> {
+        let input = examples::input(env!("CARGO_MANIFEST_DIR"), "example.txt");
+
+        numbers(&Day03::parse(&input).unwrap()).unwrap()
+    }
+
+    fn total(rule: &str) -> u64 {
+        GearRule::parse(rule)
+            .unwrap()
+            .gears(&example_numbers())
+            .iter()
+            .map(|gear| gear.ratio)
+            .sum()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(GearRule::parse("*:2:product").unwrap(), GearRule::default());
+        assert_eq!(
+            GearRule::parse("*#:1-3:sum").unwrap(),
+            GearRule {
+                symbols: vec!['*', '#'],
+                parts: 1..=3,
+                combine: Combine::Sum,
+            }
+        );
+
+        let error = GearRule::parse("*:2:max").unwrap_err();
+        assert_eq!(
+            (error.span, error.expected.as_str()),
+            (4..7, "'product' or 'sum'")
+        );
+        assert!(GearRule::parse("*:two:sum").is_err());
+        assert!(GearRule::parse(":2:sum").is_err());
+
+        let error = GearRule::parse("*:3-1:sum").unwrap_err();
+        assert_eq!(
+            (error.span, error.expected.as_str()),
+            (2..5, "a range ending at 3 or more")
+        );
+        assert!(GearRule::parse("*:2-2:sum").is_ok());
+    }
+
+    #[test]
+    fn test_gears() {
+        let gears = GearRule::default().gears(&example_numbers());
+
+        assert_eq!(gears.len(), 2);
+        assert_eq!(gears[0].to_string(), "'*' at 2:4 touches 467, 35 -> 16345");
+        assert_eq!(total("*:2:product"), 467835);
+    }
+
+    #[test]
+    fn test_variants() {
+        // The lone `*` next to 617 counts once any number of parts does.
+        assert_eq!(total("*:1-2:product"), 467835 + 617);
+        assert_eq!(total("*:2:sum"), 467 + 35 + 755 + 598);
+        // `#` touches 633, `$` 664 and `+` 592.
+        assert_eq!(total("#$+:1:sum"), 633 + 664 + 592);
+    }
+}
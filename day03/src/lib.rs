@@ -0,0 +1,274 @@
+pub mod gears;
+pub mod render;
+
+use aoc_core::{debug, error::lines, ParseError, Solution};
+use gears::GearRule;
+use std::{collections::BTreeMap, fmt::Display, ops::Range};
+
+/// The schematic, its ASCII characters stored as bytes.
+pub type Grid = aoc_core::Grid<u8>;
+
+/// A symbol with its row and column.
+pub type Symbol = (char, usize, usize);
+
+/// Any character but digits and periods.
+pub fn is_symbol(c: u8) -> bool {
+    !c.is_ascii_digit() && c != b'.'
+}
+
+/// A number of the schematic and the symbols around it. Only the numbers
+/// touching at least one symbol are part numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartNumber {
+    pub value: u32,
+    pub row: usize,
+    /// Columns of the digits of the number.
+    pub col_range: Range<usize>,
+    /// The symbols adjacent to any digit, diagonals included, in row-major
+    /// order.
+    pub adjacent_symbols: Vec<Symbol>,
+}
+
+impl PartNumber {
+    pub fn is_part(&self) -> bool {
+        !self.adjacent_symbols.is_empty()
+    }
+}
+
+/// The symbols of `grid` in the rows around `row` and the columns around
+/// `cols`, the cells of `cols` themselves included.
+fn symbols_around(grid: &Grid, row: usize, cols: &Range<usize>) -> Vec<Symbol> {
+    let rows = row.saturating_sub(1)..(row + 2).min(grid.height());
+    let cols = cols.start.saturating_sub(1)..(cols.end + 1).min(grid.width());
+    let mut symbols = Vec::new();
+
+    for row in rows {
+        let line = &grid.row(row)[cols.clone()];
+
+        for (col, &c) in (cols.start..).zip(line) {
+            if is_symbol(c) {
+                symbols.push((c as char, row, col));
+            }
+        }
+    }
+
+    symbols
+}
+
+const NUMBER_TOO_LARGE: &str = "a number that fits in a u32";
+
+/// Every number of the schematic, row by row, whether it is a part number
+/// or not. Each cell is read once, plus once per number around it. A number
+/// that doesn't fit in a `u32` is an error, which `Day03::parse` reports
+/// before any grid it returns gets here.
+pub fn numbers(grid: &Grid) -> Result<Vec<PartNumber>, ParseError> {
+    let mut numbers = Vec::new();
+
+    for (row, line) in grid.rows().enumerate() {
+        let mut col = 0;
+
+        while col < line.len() {
+            if !line[col].is_ascii_digit() {
+                col += 1;
+                continue;
+            }
+
+            let start = col;
+
+            while col < line.len() && line[col].is_ascii_digit() {
+                col += 1;
+            }
+
+            let digits = std::str::from_utf8(&line[start..col]).expect("ASCII digits");
+            let Ok(value) = digits.parse::<u32>() else {
+                let text: String = line
+                    .iter()
+                    .map(|&c| if c.is_ascii() { c as char } else { '?' })
+                    .collect();
+
+                return Err(ParseError::new(
+                    row + 1,
+                    &text,
+                    start..col,
+                    NUMBER_TOO_LARGE,
+                ));
+            };
+
+            let col_range = start..col;
+
+            numbers.push(PartNumber {
+                value,
+                row,
+                adjacent_symbols: symbols_around(grid, row, &col_range),
+                col_range,
+            });
+        }
+    }
+
+    Ok(numbers)
+}
+
+/// The part numbers touching each symbol, keyed by the symbol's position.
+pub fn numbers_by_symbol(numbers: &[PartNumber]) -> BTreeMap<(usize, usize), (char, Vec<u32>)> {
+    let mut symbols: BTreeMap<(usize, usize), (char, Vec<u32>)> = BTreeMap::new();
+
+    for number in numbers {
+        for &(symbol, row, col) in &number.adjacent_symbols {
+            symbols
+                .entry((row, col))
+                .or_insert((symbol, Vec::new()))
+                .1
+                .push(number.value);
+        }
+    }
+
+    symbols
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(input.len());
+
+        for line in lines(input) {
+            if let Some((i, c)) = line.text.char_indices().find(|(_, c)| !c.is_ascii()) {
+                let token = &line.text[i..i + c.len_utf8()];
+
+                return Err(line.error(token, "an ASCII character"));
+            }
+
+            match width {
+                None => width = Some(line.text.len()),
+                Some(width) if width != line.text.len() => {
+                    return Err(line.error(line.text, format!("a row of {} cells", width)));
+                }
+                Some(_) => {}
+            }
+
+            let runs = line.text.split(|c: char| !c.is_ascii_digit());
+
+            if let Some(run) = runs
+                .filter(|run| !run.is_empty())
+                .find(|run| run.parse::<u32>().is_err())
+            {
+                return Err(line.error(run, NUMBER_TOO_LARGE));
+            }
+
+            cells.extend_from_slice(line.text.as_bytes());
+            height += 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> impl Display {
+        numbers(grid)
+            .expect("checked while parsing")
+            .iter()
+            .filter(|number| number.is_part())
+            .map(|number| number.value as u64)
+            .sum::<u64>()
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> impl Display {
+        GearRule::default()
+            .gears(&numbers(grid).expect("checked while parsing"))
+            .iter()
+            .map(|gear| {
+                debug!("Gear {}", gear);
+
+                gear.ratio
+            })
+            .sum::<u64>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::example_tests!(Day03);
+
+    fn grid(rows: &[&str]) -> Grid {
+        Day03::parse(&rows.join("\n")).unwrap()
+    }
+
+    #[test]
+    fn test_is_symbol() {
+        assert!(!is_symbol(b'1'));
+        assert!(!is_symbol(b'.'));
+        assert!(is_symbol(b'#'));
+        assert!(is_symbol(b'='));
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid(&["1.#", "..2"]);
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.row(1), b"..2");
+
+        let error = Day03::parse("1.#\n..").unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (2, "a row of 3 cells")
+        );
+
+        let error = Day03::parse("1.é").unwrap_err();
+        assert_eq!(error.span, 2..4);
+
+        let error = Day03::parse("1...........\n.9999999999*").unwrap_err();
+        assert_eq!((error.line, error.span), (2, 1..11));
+    }
+
+    #[test]
+    fn test_numbers() {
+        let grid = grid(&["3.12..", "..#..2", "21...!", "10..4!", "10#3.."]);
+        let numbers = numbers(&grid).unwrap();
+
+        let row_sum = |row| {
+            numbers
+                .iter()
+                .filter(|number| number.row == row && number.is_part())
+                .map(|number| number.value)
+                .sum::<u32>()
+        };
+
+        assert_eq!(
+            (0..5).map(row_sum).collect::<Vec<_>>(),
+            vec![12, 2, 21, 14, 13]
+        );
+        assert_eq!(
+            numbers[1],
+            PartNumber {
+                value: 12,
+                row: 0,
+                col_range: 2..4,
+                adjacent_symbols: vec![('#', 1, 2)],
+            }
+        );
+        assert!(!numbers[0].is_part());
+
+        let grid = Grid::new(12, 1, b".9999999999*".to_vec());
+        let error = crate::numbers(&grid).unwrap_err();
+        assert_eq!(
+            (error.line, error.span, error.expected.as_str()),
+            (1, 1..11, "a number that fits in a u32")
+        );
+    }
+
+    #[test]
+    fn test_numbers_by_symbol() {
+        let grid = grid(&["467..114..", "...*......", "..35..633."]);
+        let symbols = numbers_by_symbol(&numbers(&grid).unwrap());
+
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[&(1, 3)], ('*', vec![467, 35]));
+    }
+}
@@ -1,207 +1,98 @@
 use std::{
-    collections::{HashMap, HashSet},
-    fs,
+    env,
+    io::{self, IsTerminal},
+    path::Path,
+    process,
 };
 
-type Grid = Vec<String>;
-
-fn is_special_cell(grid: &Grid, col: i32, row: i32) -> bool {
-    let width = grid[0].len() as i32;
-    let height = grid.len() as i32;
+use aoc_core::{input::InputSource, Solution};
+use day03::{
+    gears::GearRule,
+    numbers,
+    render::{render, Style},
+    Day03, Grid,
+};
 
-    if col >= width || row >= height || col < 0 || row < 0 {
-        // println!("({},{}) oob", col, row,);
+const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
 
-        return false;
-    }
+const GEARS_USAGE: &str = "Usage: day03 --gears <SYMBOLS>:<PARTS>:<product|sum> [<INPUT>|-]";
 
-    let character = grid[row as usize].chars().nth(col as usize).unwrap();
+const RENDER_USAGE: &str = "Usage: day03 --render [ansi|plain|html] [<INPUT>|-]";
 
-    // println!(
-    //     "({},{}) {} {} {}",
-    //     col,
-    //     row,
-    //     character,
-    //     !character.is_digit(10),
-    //     character != '.'
-    // );
+fn read_grid(input: Option<&Path>) -> Result<Grid, String> {
+    let source = InputSource::from_env(Day03::DAY, input, DEFAULT_INPUT);
+    let text = source
+        .read()
+        .map_err(|error| format!("Could not read {}: {}", source, error))?;
 
-    !character.is_digit(10) && character != '.'
+    Day03::parse(&text).map_err(|error| error.to_string())
 }
 
-fn is_star(grid: &Grid, col: i32, row: i32) -> bool {
-    let width = grid[0].len() as i32;
-    let height = grid.len() as i32;
-
-    if col >= width || row >= height || col < 0 || row < 0 {
-        // println!("({},{}) oob", col, row,);
-
-        return false;
-    }
-
-    let character = grid[row as usize].chars().nth(col as usize).unwrap();
+/// Prints every gear matching the rule given on the command line, then the
+/// sum of their ratios.
+fn run_gears(args: &[String]) -> Result<(), String> {
+    let (rule, input) = match args {
+        [rule] => (rule, None),
+        [rule, input] => (rule, Some(Path::new(input))),
+        _ => return Err(format!("--gears expects a rule\n\n{}", GEARS_USAGE)),
+    };
 
-    character == '*'
-}
-
-fn serialize_cell(col: i32, row: i32) -> String {
-    format!("{}:{}", col, row)
-}
+    let rule = GearRule::parse(rule).map_err(|error| error.to_string())?;
 
-fn is_special_cell_store(
-    grid: &Grid,
-    col: i32,
-    row: i32,
-    store: &mut HashMap<String, Vec<i32>>,
-    from: i32,
-) -> bool {
-    let r = is_special_cell(grid, col, row);
+    let grid = read_grid(input)?;
+    let gears = rule.gears(&numbers(&grid).expect("checked while parsing"));
 
-    let entry = store.entry(serialize_cell(col, row)).or_insert(Vec::new());
+    for gear in &gears {
+        println!("{}", gear);
+    }
 
-    entry.push(from);
+    println!("Sum: {}", gears.iter().map(|gear| gear.ratio).sum::<u64>());
 
-    r
+    Ok(())
 }
 
-fn visit_line(grid: &Grid, line_index: u32, store: &mut HashMap<String, Vec<i32>>) -> i32 {
-    let line = &grid[line_index as usize];
-
-    let mut sum: i32 = 0;
-
-    let mut current_number: String = String::from("");
-    let mut is_current_part_number = false;
-
-    let line_iter: std::str::Chars<'_> = line.chars();
-
-    let mut special_cells_buffer: HashSet<(i32, i32)> = HashSet::new();
-
-    for (i, c) in line_iter.clone().enumerate() {
-        let previous_col: i32 = i as i32 - 1;
-        let next_col: i32 = i as i32 + 1;
-        let next_row: i32 = line_index as i32 + 1;
-        let previous_row: i32 = line_index as i32 - 1;
-
-        let is_next_col_number = match line_iter.clone().nth(i + 1) {
-            Some(val) => val.is_digit(10),
-            None => false,
-        };
-
-        // println!("({}) is_next_col_number: {}", c, is_next_col_number);
-
-        if c.is_digit(10) {
-            current_number.push(c);
-
-            let cells_to_check: Vec<(i32, i32)> = vec![
-                (previous_col, line_index as i32),
-                (i as i32, previous_row),
-                (i as i32, next_row),
-                (previous_col, next_row),
-                (previous_col, previous_row),
-                (next_col, line_index as i32),
-                (next_col, next_row),
-                (next_col, previous_row),
-            ];
-
-            // println!("Checking cells: {:?}", cells_to_check);
-
-            for (col, row) in cells_to_check {
-                let is_cell_special = is_special_cell(grid, col, row) && is_star(grid, col, row);
-
-                if is_cell_special {
-                    // println!("Found special cell ({},{})", col, row);
-                    special_cells_buffer.insert((col, row));
-                }
-
-                is_current_part_number = is_current_part_number || is_cell_special;
-            }
-            // println!("is_current_part_number {}", is_current_part_number);
-        } else if c != '*' {
-            is_current_part_number = false;
-            current_number.clear();
+/// Prints the schematic annotated with the puzzle's part numbers and gears,
+/// in colour when writing to a terminal unless a style is given.
+fn run_render(args: &[String]) -> Result<(), String> {
+    let (style, input) = match args.first().map(|arg| Style::parse(arg)) {
+        Some(Some(style)) => (Some(style), &args[1..]),
+        _ => (None, args),
+    };
+
+    let input = match input {
+        [] => None,
+        [input] => Some(Path::new(input)),
+        _ => return Err(format!("Unexpected arguments\n\n{}", RENDER_USAGE)),
+    };
+
+    let style = style.unwrap_or_else(|| {
+        if io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none() {
+            Style::Ansi
         } else {
-            // special_cells_buffer.push((i as i32, line_index as i32));
-            is_current_part_number = true;
-        }
-
-        if !is_next_col_number && is_current_part_number {
-            // println!("Line #{}: {} += {}", line_index, sum, current_number);
-            if let Ok(parsed_current_number) = current_number.parse::<i32>() {
-                println!(
-                    "Adding {} to {:?}",
-                    parsed_current_number, special_cells_buffer
-                );
-                for (col, row) in special_cells_buffer.clone() {
-                    store
-                        .entry(serialize_cell(col, row))
-                        .or_insert(Vec::new())
-                        .push(parsed_current_number);
-                }
-                sum += parsed_current_number;
-            }
-
-            special_cells_buffer.clear();
-            current_number.clear();
-            is_current_part_number = false;
+            Style::Plain
         }
-    }
-
-    return sum;
-}
-
-fn main() {
-    let input = fs::read_to_string("./input.txt").unwrap();
-
-    let grid: Vec<String> = input.lines().map(|line: &str| line.to_string()).collect();
-
-    let store = &mut HashMap::new();
-
-    let answer1 = input.lines().enumerate().fold(0, |sum, (line_index, _)| {
-        let line_value: i32 = visit_line(&grid, line_index as u32, store);
-        // println!("Line #{}\tvalue = {}\tsum={}", line_index, line_value, sum);
-        sum + line_value
     });
 
-    let mut answer2 = 0;
+    let grid = read_grid(input)?;
+    let numbers = numbers(&grid).expect("checked while parsing");
+    let gears = GearRule::default().gears(&numbers);
 
-    for (cell, numbers) in store {
-        if numbers.len() == 2 {
-            answer2 += numbers[0] * numbers[1];
-        }
-    }
+    print!("{}", render(&grid, &numbers, &gears, style));
 
-    println!("Answer 1: {}", answer1);
-    println!("Answer 2: {}", answer2);
+    Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_check_cell() {
-        let grid = Vec::from([String::from("12.."), String::from("#=.2")]);
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
 
-        assert_eq!(is_special_cell(&grid, 0, 0), false);
-        assert_eq!(is_special_cell(&grid, 0, 2), false);
-        assert_eq!(is_special_cell(&grid, 0, 1), true);
-        assert_eq!(is_special_cell(&grid, 1, 1), true);
-    }
+    let command = match args.first().map(String::as_str) {
+        Some("--gears") => run_gears,
+        Some("--render") => run_render,
+        _ => return aoc_core::run::<Day03>(DEFAULT_INPUT),
+    };
 
-    #[test]
-    fn test_visit_line() {
-        let grid = Vec::from([
-            String::from("3.12.."),
-            String::from("..#..2"),
-            String::from("21...!"),
-            String::from("10..4!"),
-            String::from("10#3.."),
-        ]);
-
-        assert_eq!(visit_line(&grid, 0), 12);
-        assert_eq!(visit_line(&grid, 1), 2);
-        assert_eq!(visit_line(&grid, 2), 21);
-        assert_eq!(visit_line(&grid, 3), 14);
-        assert_eq!(visit_line(&grid, 4), 13);
+    if let Err(error) = command(&args[1..]) {
+        eprintln!("{}", error);
+        process::exit(2);
     }
 }
@@ -0,0 +1,240 @@
+//! Draws the schematic with what the solver made of it: part numbers, other
+//! numbers, symbols and gears, from the same extraction as the answers.
+
+use std::collections::HashSet;
+
+use crate::{gears::Gear, is_symbol, Grid, PartNumber};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Colours for a terminal.
+    Ansi,
+    /// The schematic as is, each row followed by what it holds.
+    Plain,
+    Html,
+}
+
+impl Style {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "ansi" => Some(Style::Ansi),
+            "plain" => Some(Style::Plain),
+            "html" => Some(Style::Html),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Blank,
+    Part,
+    NotPart,
+    Symbol,
+    Gear,
+}
+
+impl Kind {
+    fn ansi(self) -> Option<&'static str> {
+        match self {
+            Kind::Blank => None,
+            Kind::Part => Some("\x1b[32m"),
+            Kind::NotPart => Some("\x1b[31m"),
+            Kind::Symbol => Some("\x1b[33m"),
+            Kind::Gear => Some("\x1b[1;35m"),
+        }
+    }
+
+    fn class(self) -> Option<&'static str> {
+        match self {
+            Kind::Blank => None,
+            Kind::Part => Some("part"),
+            Kind::NotPart => Some("not-part"),
+            Kind::Symbol => Some("symbol"),
+            Kind::Gear => Some("gear"),
+        }
+    }
+}
+
+/// What every cell of `grid` is.
+fn kinds(grid: &Grid, numbers: &[PartNumber], gears: &[Gear]) -> Vec<Vec<Kind>> {
+    let gears: HashSet<(usize, usize)> = gears.iter().map(|gear| (gear.row, gear.col)).collect();
+
+    let mut kinds: Vec<Vec<Kind>> = grid
+        .rows()
+        .enumerate()
+        .map(|(row, line)| {
+            line.iter()
+                .enumerate()
+                .map(|(col, &c)| match c {
+                    _ if gears.contains(&(row, col)) => Kind::Gear,
+                    c if is_symbol(c) => Kind::Symbol,
+                    _ => Kind::Blank,
+                })
+                .collect()
+        })
+        .collect();
+
+    for number in numbers {
+        let kind = if number.is_part() {
+            Kind::Part
+        } else {
+            Kind::NotPart
+        };
+
+        kinds[number.row][number.col_range.clone()].fill(kind);
+    }
+
+    kinds
+}
+
+/// The runs of cells of the same kind in a row.
+fn runs<'a>(line: &'a [u8], kinds: &'a [Kind]) -> impl Iterator<Item = (Kind, String)> + 'a {
+    let mut start = 0;
+
+    (1..=line.len()).filter_map(move |end| {
+        if end < line.len() && kinds[end] == kinds[start] {
+            return None;
+        }
+
+        let run = (
+            kinds[start],
+            line[start..end].iter().map(|&c| c as char).collect(),
+        );
+        start = end;
+
+        Some(run)
+    })
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// The numbers of a row, as listed after it in plain text.
+fn row_summary(row: usize, numbers: &[PartNumber], gears: &[Gear]) -> String {
+    let list = |values: Vec<String>| values.join(", ");
+
+    let parts = list(
+        numbers
+            .iter()
+            .filter(|number| number.row == row && number.is_part())
+            .map(|number| number.value.to_string())
+            .collect(),
+    );
+    let not_parts = list(
+        numbers
+            .iter()
+            .filter(|number| number.row == row && !number.is_part())
+            .map(|number| number.value.to_string())
+            .collect(),
+    );
+    let gears = list(
+        gears
+            .iter()
+            .filter(|gear| gear.row == row)
+            .map(|gear| format!("{}@{}", gear.ratio, gear.col + 1))
+            .collect(),
+    );
+
+    [("parts", parts), ("not parts", not_parts), ("gears", gears)]
+        .into_iter()
+        .filter(|(_, values)| !values.is_empty())
+        .map(|(label, values)| format!("{}: {}", label, values))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+pub fn render(grid: &Grid, numbers: &[PartNumber], gears: &[Gear], style: Style) -> String {
+    let kinds = kinds(grid, numbers, gears);
+    let mut out = String::new();
+
+    if style == Style::Html {
+        out.push_str("<pre class=\"schematic\">\n");
+    }
+
+    for (row, line) in grid.rows().enumerate() {
+        for (kind, text) in runs(line, &kinds[row]) {
+            match style {
+                Style::Ansi => match kind.ansi() {
+                    Some(colour) => out.push_str(&format!("{}{}\x1b[0m", colour, text)),
+                    None => out.push_str(&text),
+                },
+                Style::Html => match kind.class() {
+                    Some(class) => out.push_str(&format!(
+                        "<span class=\"{}\">{}</span>",
+                        class,
+                        escape_html(&text)
+                    )),
+                    None => out.push_str(&escape_html(&text)),
+                },
+                Style::Plain => out.push_str(&text),
+            }
+        }
+
+        if style == Style::Plain {
+            let summary = row_summary(row, numbers, gears);
+
+            if !summary.is_empty() {
+                out.push_str("  ");
+                out.push_str(&summary);
+            }
+        }
+
+        out.push('\n');
+    }
+
+    if style == Style::Html {
+        out.push_str("</pre>\n");
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{gears::GearRule, numbers, Day03};
+    use aoc_core::Solution;
+
+    fn render_rows(rows: &[&str], style: Style) -> String {
+        let grid = Day03::parse(&rows.join("\n")).unwrap();
+        let numbers = numbers(&grid).unwrap();
+        let gears = GearRule::default().gears(&numbers);
+
+        render(&grid, &numbers, &gears, style)
+    }
+
+    #[test]
+    fn test_render_ansi() {
+        assert_eq!(
+            render_rows(&["1..22", "*.#..", "3...."], Style::Ansi),
+            "\x1b[32m1\x1b[0m..\x1b[32m22\x1b[0m\n\
+             \x1b[1;35m*\x1b[0m.\x1b[33m#\x1b[0m..\n\
+             \x1b[32m3\x1b[0m....\n"
+        );
+        assert!(render_rows(&["4.."], Style::Ansi).starts_with("\x1b[31m4\x1b[0m"));
+    }
+
+    #[test]
+    fn test_render_plain() {
+        assert_eq!(
+            render_rows(&["1..22.7", "*.#....", "3......"], Style::Plain),
+            "1..22.7  parts: 1, 22; not parts: 7\n\
+             *.#....  gears: 3@1\n\
+             3......  parts: 3\n"
+        );
+    }
+
+    #[test]
+    fn test_render_html() {
+        assert_eq!(
+            render_rows(&["5<"], Style::Html),
+            "<pre class=\"schematic\">\n\
+             <span class=\"part\">5</span><span class=\"symbol\">&lt;</span>\n\
+             </pre>\n"
+        );
+    }
+}
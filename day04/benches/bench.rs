@@ -0,0 +1,3 @@
+fn main() {
+    aoc_core::bench::main::<day04::Day04>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,247 @@
+//! Why the cards add up to what they do: which cards each card wins copies
+//! of, how many cards every original ends up producing, and what the total
+//! would be if a card read differently.
+
+use std::{
+    cmp::Reverse,
+    error::Error,
+    fmt::{self, Display},
+    ops::Range,
+};
+
+use aoc_core::{Line, ParseError};
+
+use crate::{copies, total_copies, CopiesOverflow, Scratchcard};
+
+/// What a single card does during the game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardTrace {
+    pub id: u32,
+    pub matches: usize,
+    /// Ids of the cards one instance of this card wins a copy of.
+    pub won: Vec<u32>,
+    /// Instances of this card at the end, the original included.
+    pub copies: u64,
+    /// Cards one instance of this card yields, itself and every copy won
+    /// down the line included.
+    pub produced: u64,
+}
+
+/// The indexes of the cards the card at `i` wins copies of.
+fn won(cards: &[Scratchcard], i: usize) -> Range<usize> {
+    i + 1..(i + 1 + cards[i].matches()).min(cards.len())
+}
+
+/// Why a what-if game has no total: the edited card doesn't hold up as a
+/// card, or the copies overflow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WhatIfError {
+    Card(ParseError),
+    Copies(CopiesOverflow),
+}
+
+impl Display for WhatIfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Card(error) => error.fmt(f),
+            Self::Copies(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for WhatIfError {}
+
+impl From<ParseError> for WhatIfError {
+    fn from(error: ParseError) -> Self {
+        Self::Card(error)
+    }
+}
+
+impl From<CopiesOverflow> for WhatIfError {
+    fn from(error: CopiesOverflow) -> Self {
+        Self::Copies(error)
+    }
+}
+
+/// The cards of a game, which can be edited to see how the outcome changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Simulation {
+    cards: Vec<Scratchcard>,
+}
+
+impl Simulation {
+    pub fn new(cards: Vec<Scratchcard>) -> Self {
+        Self { cards }
+    }
+
+    pub fn cards(&self) -> &[Scratchcard] {
+        &self.cards
+    }
+
+    pub fn total(&self) -> Result<u64, CopiesOverflow> {
+        total_copies(&self.cards)
+    }
+
+    /// Every card in order. Cards only win copies of later cards, so what a
+    /// card produces is settled by going through them backwards.
+    pub fn trace(&self) -> Result<Vec<CardTrace>, CopiesOverflow> {
+        let copies = copies(&self.cards)?;
+        let mut produced = vec![1u64; self.cards.len()];
+
+        for i in (0..self.cards.len()).rev() {
+            for j in won(&self.cards, i) {
+                produced[i] = produced[i].checked_add(produced[j]).ok_or(CopiesOverflow {
+                    card: self.cards[i].id,
+                })?;
+            }
+        }
+
+        Ok(self
+            .cards
+            .iter()
+            .enumerate()
+            .map(|(i, card)| CardTrace {
+                id: card.id,
+                matches: card.matches(),
+                won: won(&self.cards, i).map(|j| self.cards[j].id).collect(),
+                copies: copies[i],
+                produced: produced[i],
+            })
+            .collect())
+    }
+
+    /// The `count` original cards producing the most cards, most first, ties
+    /// in card order.
+    pub fn most_productive(&self, count: usize) -> Result<Vec<CardTrace>, CopiesOverflow> {
+        let mut trace = self.trace()?;
+
+        trace.sort_by_key(|card| Reverse(card.produced));
+        trace.truncate(count);
+
+        Ok(trace)
+    }
+
+    /// Replaces the numbers of card `id`, returning whether there is one.
+    /// The edited card is read back as the line it would be in the input,
+    /// so it is held to the same rules as a parsed card.
+    pub fn edit(&mut self, id: u32, winning: Vec<u32>, have: Vec<u32>) -> Result<bool, ParseError> {
+        let Some(i) = self.cards.iter().position(|card| card.id == id) else {
+            return Ok(false);
+        };
+
+        let join = |numbers: &[u32]| {
+            numbers
+                .iter()
+                .map(|number| number.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let text = format!("Card {}: {} | {}", id, join(&winning), join(&have));
+
+        self.cards[i] = Scratchcard::parse(Line::new(i + 1, &text))?;
+
+        Ok(true)
+    }
+
+    /// The total if card `id` had these numbers, leaving this game as is.
+    /// `None` if there is no such card.
+    pub fn what_if(
+        &self,
+        id: u32,
+        winning: Vec<u32>,
+        have: Vec<u32>,
+    ) -> Option<Result<u64, WhatIfError>> {
+        let mut simulation = self.clone();
+
+        match simulation.edit(id, winning, have) {
+            Ok(true) => Some(simulation.total().map_err(WhatIfError::from)),
+            Ok(false) => None,
+            Err(error) => Some(Err(error.into())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day04;
+    use aoc_core::{examples, Solution};
+
+    fn example() -> Simulation {
+        let input = examples::input(env!("CARGO_MANIFEST_DIR"), "example.txt");
+
+        Simulation::new(Day04::parse(&input).unwrap())
+    }
+
+    #[test]
+    fn test_trace() {
+        let trace = example().trace().unwrap();
+
+        assert_eq!(trace[0].won, vec![2, 3, 4, 5]);
+        assert_eq!(trace[4].won, Vec::<u32>::new());
+        assert_eq!(
+            trace.iter().map(|card| card.copies).collect::<Vec<_>>(),
+            vec![1, 2, 4, 8, 14, 1]
+        );
+        assert_eq!(
+            trace.iter().map(|card| card.produced).collect::<Vec<_>>(),
+            vec![15, 7, 4, 2, 1, 1]
+        );
+        // Every instance traces back to exactly one original.
+        assert_eq!(trace.iter().map(|card| card.produced).sum::<u64>(), 30);
+    }
+
+    #[test]
+    fn test_most_productive() {
+        let most = example().most_productive(2).unwrap();
+
+        assert_eq!(
+            most.iter().map(|card| card.id).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn test_what_if() {
+        let mut simulation = example();
+
+        // Card 1 no longer winning anything.
+        assert_eq!(simulation.what_if(1, vec![1], vec![2]), Some(Ok(16)));
+        // Card 6 winning a copy of a card that does not exist.
+        assert_eq!(simulation.what_if(6, vec![1], vec![1]), Some(Ok(30)));
+        assert_eq!(simulation.what_if(7, vec![1], vec![1]), None);
+        assert_eq!(simulation.total(), Ok(30));
+
+        assert_eq!(simulation.edit(5, vec![1], vec![1]), Ok(true));
+        assert_eq!(simulation.total(), Ok(44));
+    }
+
+    #[test]
+    fn test_what_if_invalid_card() {
+        let simulation = example();
+
+        let Some(Err(WhatIfError::Card(error))) = simulation.what_if(1, vec![5], vec![5, 5]) else {
+            panic!("a card with a number twice");
+        };
+        assert_eq!(
+            (
+                error.line,
+                &error.text[error.span.clone()],
+                error.expected.as_str()
+            ),
+            (1, "5", "a number not already on this side")
+        );
+
+        let Some(Err(WhatIfError::Card(error))) = simulation.what_if(2, vec![], vec![1]) else {
+            panic!("a card without winning numbers");
+        };
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (2, "at least one number")
+        );
+
+        let mut edited = simulation.clone();
+        assert!(edited.edit(3, vec![1, 1], vec![1]).is_err());
+        assert_eq!(edited, simulation);
+    }
+}
@@ -0,0 +1,326 @@
+pub mod analysis;
+
+use aoc_core::{error::lines, Line, ParseError, Solution};
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scratchcard {
+    pub id: u32,
+    /// The winning numbers, left of the `|`.
+    pub winning: Vec<u32>,
+    /// The numbers we have, right of the `|`.
+    pub have: Vec<u32>,
+}
+
+impl Scratchcard {
+    /// Parses `Card <id>: <winning> | <have>`, whatever the width of the id.
+    /// Both sides must hold at least one number, and no number twice.
+    pub fn parse(line: Line) -> Result<Self, ParseError> {
+        let (card, numbers) = line.split_once(line.text, ":")?;
+        let id = line.number(line.strip_prefix(card, "Card")?.trim())?;
+        let (winning, have) = line.split_once(numbers, "|")?;
+
+        Ok(Self {
+            id,
+            winning: Self::parse_side(&line, winning)?,
+            have: Self::parse_side(&line, have)?,
+        })
+    }
+
+    fn parse_side(line: &Line, side: &str) -> Result<Vec<u32>, ParseError> {
+        let mut numbers: Vec<u32> = Vec::new();
+
+        for token in side.split_whitespace() {
+            let number = line.number(token)?;
+
+            if numbers.contains(&number) {
+                return Err(line.error(token, "a number not already on this side"));
+            }
+
+            numbers.push(number);
+        }
+
+        if numbers.is_empty() {
+            return Err(line.error_after(side, "at least one number"));
+        }
+
+        Ok(numbers)
+    }
+
+    /// How many of the numbers we have are winning numbers.
+    pub fn matches(&self) -> usize {
+        self.have
+            .iter()
+            .filter(|number| self.winning.contains(number))
+            .count()
+    }
+
+    /// 1 point for the first match, doubled for every other one, or `None`
+    /// past 64 matches, when the points no longer fit in a `u64`.
+    pub fn points(&self) -> Option<u64> {
+        match self.matches() {
+            0 => Some(0),
+            matches => 1u64.checked_shl((matches - 1) as u32),
+        }
+    }
+}
+
+/// A card won more copies than a `u64` can count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CopiesOverflow {
+    pub card: u32,
+}
+
+impl fmt::Display for CopiesOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Copies of card {} overflow a u64", self.card)
+    }
+}
+
+impl Error for CopiesOverflow {}
+
+/// How many instances of each card we end up with, originals included.
+/// Cards only win copies of the cards after them, so a single pass in order
+/// settles every count before it is handed on.
+pub fn copies(cards: &[Scratchcard]) -> Result<Vec<u64>, CopiesOverflow> {
+    let mut copies = vec![1u64; cards.len()];
+
+    for (i, card) in cards.iter().enumerate() {
+        let won = i + 1..(i + 1 + card.matches()).min(cards.len());
+
+        for j in won {
+            copies[j] = copies[j]
+                .checked_add(copies[i])
+                .ok_or(CopiesOverflow { card: cards[j].id })?;
+        }
+    }
+
+    Ok(copies)
+}
+
+/// The total number of cards, originals included.
+pub fn total_copies(cards: &[Scratchcard]) -> Result<u64, CopiesOverflow> {
+    let copies = copies(cards)?;
+
+    copies
+        .iter()
+        .zip(cards)
+        .try_fold(0u64, |total, (&copies, card)| {
+            total
+                .checked_add(copies)
+                .ok_or(CopiesOverflow { card: card.id })
+        })
+}
+
+/// The matches and copies of every card, one row per card.
+pub fn copy_table(cards: &[Scratchcard]) -> Result<String, CopiesOverflow> {
+    let mut table = format!("{:>6} {:>7} {:>20}\n", "Card", "Matches", "Copies");
+
+    for (card, copies) in cards.iter().zip(copies(cards)?) {
+        table.push_str(&format!(
+            "{:>6} {:>7} {:>20}\n",
+            card.id,
+            card.matches(),
+            copies
+        ));
+    }
+
+    table.push_str(&format!(
+        "{:>6} {:>7} {:>20}\n",
+        "Total",
+        "",
+        total_copies(cards)?
+    ));
+
+    Ok(table)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<Scratchcard>;
+
+    /// Also rejects the card from which the total points or copies would
+    /// overflow a `u64`, so that neither part has to fail.
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let lines: Vec<Line> = lines(input).collect();
+        let cards: Vec<Scratchcard> = lines
+            .iter()
+            .map(|&line| Scratchcard::parse(line))
+            .collect::<Result<_, _>>()?;
+
+        let mut points = 0u64;
+
+        for (line, card) in lines.iter().zip(&cards) {
+            points = card
+                .points()
+                .and_then(|card_points| points.checked_add(card_points))
+                .ok_or_else(|| line.error(line.text, "a card whose points fit in a u64"))?;
+        }
+
+        if let Err(error) = total_copies(&cards) {
+            let line = cards
+                .iter()
+                .position(|card| card.id == error.card)
+                .map(|i| lines[i])
+                .expect("the overflowing card is one of the cards");
+
+            return Err(line.error(line.text, "a card whose copies fit in a u64"));
+        }
+
+        Ok(cards)
+    }
+
+    fn part1(cards: &Self::Input<'_>) -> impl Display {
+        cards
+            .iter()
+            .map(|card| card.points().expect("checked while parsing"))
+            .sum::<u64>()
+    }
+
+    fn part2(cards: &Self::Input<'_>) -> impl Display {
+        total_copies(cards).expect("checked while parsing")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::examples;
+
+    aoc_core::example_tests!(Day04);
+
+    fn card(text: &str) -> Scratchcard {
+        Scratchcard::parse(Line::new(1, text)).unwrap()
+    }
+
+    #[test]
+    fn test_points() {
+        assert_eq!(
+            card("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").points(),
+            Some(8)
+        );
+        assert_eq!(
+            card("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36").points(),
+            Some(0)
+        );
+        assert_eq!(
+            card("Card 3: 1 21 53 59 44 | 69 82 63 72 16 21 14  1").matches(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let first = card("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53");
+
+        assert_eq!(first.id, 1);
+        assert_eq!(first.winning, vec![41, 48, 83, 86, 17]);
+        assert_eq!(first.have.len(), 8);
+
+        assert_eq!(card("Card   12: 1 | 2").id, 12);
+        assert_eq!(card("Card 123456:1|2").id, 123456);
+    }
+
+    #[test]
+    fn test_copies() {
+        let input = examples::input(env!("CARGO_MANIFEST_DIR"), "example.txt");
+        let cards = Day04::parse(&input).unwrap();
+
+        assert_eq!(copies(&cards), Ok(vec![1, 2, 4, 8, 14, 1]));
+        assert_eq!(total_copies(&cards), Ok(30));
+        let table = copy_table(&cards).unwrap();
+        let rows: Vec<Vec<&str>> = table
+            .lines()
+            .map(|row| row.split_whitespace().collect())
+            .collect();
+
+        assert_eq!(rows[0], ["Card", "Matches", "Copies"]);
+        assert_eq!(rows[5], ["5", "0", "14"]);
+        assert_eq!(rows[7], ["Total", "30"]);
+    }
+
+    #[test]
+    fn test_copies_long_lists() {
+        // Every card wins the next one: no recursion, and no overflow.
+        let cards: Vec<Scratchcard> = (1..=200_000)
+            .map(|id| Scratchcard {
+                id,
+                winning: vec![1],
+                have: vec![1],
+            })
+            .collect();
+
+        assert_eq!(copies(&cards).unwrap()[199_999], 200_000);
+        assert_eq!(total_copies(&cards), Ok(200_000 * 200_001 / 2));
+    }
+
+    #[test]
+    fn test_copies_overflow() {
+        // Every card wins copies of the next two, doubling them and more.
+        let cards: Vec<Scratchcard> = (1..=100)
+            .map(|id| Scratchcard {
+                id,
+                winning: vec![1, 2],
+                have: vec![1, 2],
+            })
+            .collect();
+
+        assert_eq!(copies(&cards).unwrap_err().card, 92);
+
+        let input: String = (1..=100)
+            .map(|id| format!("Card {}: 1 2 | 1 2\n", id))
+            .collect();
+        let error = Day04::parse(&input).unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (92, "a card whose copies fit in a u64")
+        );
+    }
+
+    #[test]
+    fn test_points_overflow() {
+        let numbers = |n: u32| (1..=n).map(|i| i.to_string()).collect::<Vec<_>>().join(" ");
+        let card_with = |n| card(&format!("Card 1: {} | {}", numbers(n), numbers(n)));
+
+        assert_eq!(card_with(64).points(), Some(1 << 63));
+        assert_eq!(card_with(65).points(), None);
+
+        let input = format!("Card 1: 1 | 1\nCard 2: {0} | {0}\n", numbers(65));
+        let error = Day04::parse(&input).unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (2, "a card whose points fit in a u64")
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let parse = |text| Scratchcard::parse(Line::new(2, text)).unwrap_err();
+
+        let error = parse("Card 2: 41 48 | 83 8x");
+        assert_eq!((error.line, error.span), (2, 19..21));
+
+        let error = parse("Card 1: 41 48 83");
+        assert_eq!((error.span, error.expected.as_str()), (16..16, "'|'"));
+
+        assert_eq!(parse("Card 1 41 48 | 83").expected, "':'");
+        assert_eq!(parse("Game 1: 41 48 | 83").expected, "'Card'");
+        assert_eq!(parse("Card x: 41 48 | 83").span, 5..6);
+
+        let error = parse("Card 1: 41 48 41 | 83");
+        assert_eq!(
+            (error.span, error.expected.as_str()),
+            (14..16, "a number not already on this side")
+        );
+
+        let error = parse("Card 1: 41 48 |   ");
+        assert_eq!(error.expected, "at least one number");
+        assert!(parse("Card 1:  | 41").expected.contains("at least one"));
+    }
+}
@@ -1,131 +1,42 @@
-use std::{collections::HashSet, fs};
+use std::{env, path::Path, process};
 
-fn parse_line(line: &str) -> (HashSet<u32>, Vec<u32>) {
-    let parts = &line[10..line.len()].split(" | ").collect::<Vec<&str>>();
+use aoc_core::{input::InputSource, Solution};
+use day04::{copy_table, Day04};
 
-    let (winning_numbers, tickets) = (
-        parts[0]
-            .split(" ")
-            .filter(|n: &&str| n != &"")
-            .map(|n| n.parse::<u32>().unwrap())
-            .collect::<HashSet<u32>>(),
-        parts[1]
-            .split(" ")
-            .filter(|n: &&str| n != &"")
-            .map(|n| n.parse::<u32>().unwrap())
-            .collect::<Vec<u32>>(),
-    );
+const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
 
-    (winning_numbers, tickets)
-}
-
-fn get_line_value(line: &str) -> u32 {
-    let (winning_numbers, tickets) = parse_line(line);
-    let amount_winning_tickets: i32 = get_matching_numbers(&winning_numbers, &tickets);
-
-    let base: i32 = 2;
-
-    let power = std::cmp::max(amount_winning_tickets - 1, 0);
+const COPIES_USAGE: &str = "Usage: day04 --copies [<INPUT>|-]";
 
-    if amount_winning_tickets == 0 {
-        return 0;
-    }
+/// Prints how many matches and copies every card has.
+fn run_copies(args: &[String]) -> Result<(), String> {
+    let input = match args {
+        [] => None,
+        [input] => Some(Path::new(input)),
+        _ => return Err(format!("Unexpected arguments\n\n{}", COPIES_USAGE)),
+    };
 
-    base.pow(power as u32) as u32
-}
+    let source = InputSource::from_env(Day04::DAY, input, DEFAULT_INPUT);
+    let text = source
+        .read()
+        .map_err(|error| format!("Could not read {}: {}", source, error))?;
+    let cards = Day04::parse(&text).map_err(|error| error.to_string())?;
 
-fn get_matching_numbers(winning_numbers: &HashSet<u32>, tickets: &Vec<u32>) -> i32 {
-    let matching_numbers = tickets.iter().fold(0, |sum, ticket| {
-        if winning_numbers.contains(ticket) {
-            sum + 1
-        } else {
-            sum
-        }
-    });
+    print!("{}", copy_table(&cards).map_err(|error| error.to_string())?);
 
-    matching_numbers
-}
-
-fn get_stratchcards(
-    cards: &Vec<(HashSet<u32>, Vec<u32>)>,
-    card_number: u32,
-    memo: &mut Vec<i32>,
-) -> i32 {
-    if card_number >= cards.len() as u32 {
-        return 0;
-    }
-
-    if memo[card_number as usize] != -1 {
-        return memo[card_number as usize];
-    }
-
-    let (winning_numbers, tickets) = cards.get(card_number as usize).unwrap();
-
-    let matching_numbers = get_matching_numbers(winning_numbers, tickets);
-
-    // println!(
-    //     "Card #{} matching numbers: {}, wn={:?} tickets={:?}",
-    //     card_number, matching_numbers, winning_numbers, tickets
-    // );
-
-    let mut stretchcards: i32 = 1;
-
-    for i in 1..=matching_numbers {
-        stretchcards += get_stratchcards(cards, card_number + i as u32, memo);
-    }
-
-    (*memo)[card_number as usize] = stretchcards;
-
-    stretchcards
+    Ok(())
 }
 
 fn main() {
-    let input = fs::read_to_string("./input.txt").unwrap();
-
-    let answer1: u32 = input
-        .lines()
-        .fold(0, |sum, line| sum + get_line_value(line));
-
-    let cards: Vec<(HashSet<u32>, Vec<u32>)> = input
-        .lines()
-        .map(|line| parse_line(line))
-        .collect::<Vec<(HashSet<u32>, Vec<u32>)>>();
-
-    let mut stretchcards = 0;
-
-    let mut memo: Vec<i32> = vec![-1; cards.len()];
+    let args: Vec<String> = env::args().skip(1).collect();
 
-    for i in 0..cards.len() {
-        stretchcards += get_stratchcards(&cards, i as u32, &mut memo);
-    }
-    // stretchcards += get_stratchcards(&cards, 0 as u32);
-
-    println!("Answer 1: {}", answer1);
-    println!("Answer 2: {}", stretchcards);
-}
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_get_line_value() {
-        assert_eq!(
-            get_line_value("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53"),
-            8
-        );
+    if args.first().is_some_and(|arg| arg == "--copies") {
+        if let Err(error) = run_copies(&args[1..]) {
+            eprintln!("{}", error);
+            process::exit(2);
+        }
 
-        assert_eq!(
-            get_line_value("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36"),
-            0
-        );
+        return;
     }
 
-    #[test]
-    fn test_parse_line() {
-        let (winning_numbers, tickets) =
-            parse_line("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53");
-
-        assert_eq!(winning_numbers.len(), 5);
-        assert_eq!(tickets.len(), 8);
-    }
+    aoc_core::run::<Day04>(DEFAULT_INPUT);
 }
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_core::bench::main::<day05::Day05>(env!("CARGO_MANIFEST_DIR"));
+}
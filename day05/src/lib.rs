@@ -0,0 +1,485 @@
+use aoc_core::{debug, error::lines, Line, ParseError, Solution};
+use std::{fmt::Display, ops::Range};
+
+#[derive(Debug)]
+pub struct MapperGroup {
+    name: String,
+    mappers: Vec<Mapper>,
+}
+
+impl MapperGroup {
+    fn new() -> Self {
+        Self {
+            name: String::new(),
+            mappers: Vec::new(),
+        }
+    }
+
+    /// Maps whole intervals of values at once: each is split where mappers
+    /// start and end, every piece inside a mapper is shifted and the pieces
+    /// outside any mapper are kept as is. The result is sorted and merged.
+    /// Parsing keeps every mapper's ranges within a `u64`, so none of the
+    /// shifts overflow.
+    fn map_intervals(&self, intervals: &[Range<u64>]) -> Vec<Range<u64>> {
+        let mut unmapped: Vec<Range<u64>> = intervals.to_vec();
+        let mut mapped: Vec<Range<u64>> = Vec::new();
+
+        for mapper in &self.mappers {
+            let source = mapper.source..mapper.source + mapper.range;
+            let mut outside = Vec::new();
+
+            for interval in unmapped {
+                let start = interval.start.max(source.start);
+                let end = interval.end.min(source.end);
+
+                if start >= end {
+                    outside.push(interval);
+                    continue;
+                }
+
+                mapped.push(
+                    start - mapper.source + mapper.destination
+                        ..end - mapper.source + mapper.destination,
+                );
+
+                if interval.start < start {
+                    outside.push(interval.start..start);
+                }
+
+                if end < interval.end {
+                    outside.push(end..interval.end);
+                }
+            }
+
+            unmapped = outside;
+        }
+
+        mapped.extend(unmapped);
+
+        merge(mapped)
+    }
+}
+
+/// Sorts `intervals` and merges those overlapping or touching, so that the
+/// number of intervals does not grow needlessly from one group to the next.
+fn merge(mut intervals: Vec<Range<u64>>) -> Vec<Range<u64>> {
+    intervals.retain(|interval| !interval.is_empty());
+    intervals.sort_by_key(|interval| interval.start);
+
+    let mut merged: Vec<Range<u64>> = Vec::with_capacity(intervals.len());
+
+    for interval in intervals {
+        match merged.last_mut() {
+            Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+            _ => merged.push(interval),
+        }
+    }
+
+    merged
+}
+
+#[derive(Debug)]
+pub struct Mapper {
+    destination: u64,
+    source: u64,
+    range: u64,
+}
+
+impl Mapper {
+    fn new(destination: u64, source: u64, range: u64) -> Self {
+        Self {
+            destination,
+            source,
+            range,
+        }
+    }
+}
+
+const RANGE_TOO_LONG: &str = "a length that keeps the range within a u64";
+
+/// Parses `seeds: <start> <length> ...`. Part 2 reads the numbers as pairs,
+/// so there must be at least one pair and no number left over, and every
+/// range must end within a `u64`.
+fn parse_seeds(line: Line) -> Result<Vec<u64>, ParseError> {
+    let numbers = line.strip_prefix(line.text, "seeds:")?;
+    let tokens: Vec<&str> = numbers.split_whitespace().collect();
+    let mut seeds: Vec<u64> = Vec::with_capacity(tokens.len());
+
+    for pair in tokens.chunks(2) {
+        let start: u64 = line.number(pair[0])?;
+        let Some(&length) = pair.get(1) else {
+            return Err(line.error_after(pair[0], "the length of the seed range"));
+        };
+        let length: u64 = line.number(length)?;
+
+        if start.checked_add(length).is_none() {
+            return Err(line.error(pair[1], RANGE_TOO_LONG));
+        }
+
+        seeds.extend([start, length]);
+    }
+
+    if seeds.is_empty() {
+        return Err(line.error_after(numbers, "at least one seed"));
+    }
+
+    Ok(seeds)
+}
+
+fn parse_almanac(input: &str) -> Result<(Vec<u64>, Vec<MapperGroup>), ParseError> {
+    let mut line_iter = lines(input);
+
+    let seed_line = line_iter
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(1, "'seeds:'"))?;
+
+    let seeds = parse_seeds(seed_line)?;
+
+    if let Some(line) = line_iter.next() {
+        if !line.text.is_empty() {
+            return Err(line.error(line.text, "an empty line"));
+        }
+    }
+
+    let mut current_mapper_group: MapperGroup = MapperGroup::new();
+
+    let mut almanac: Vec<MapperGroup> = Vec::new();
+
+    for line in line_iter {
+        if line.text.is_empty() {
+            almanac.push(current_mapper_group);
+            current_mapper_group = MapperGroup::new();
+        } else if let Some(name) = line.text.strip_suffix(" map:") {
+            current_mapper_group.name = name.to_string();
+        } else {
+            let tokens: Vec<&str> = line.text.split_whitespace().collect();
+
+            let [destination, source, range] = tokens[..] else {
+                return Err(line.error(line.text, "three numbers"));
+            };
+
+            let current_mapper = Mapper::new(
+                line.number(destination)?,
+                line.number(source)?,
+                line.number(range)?,
+            );
+
+            if current_mapper
+                .source
+                .checked_add(current_mapper.range)
+                .is_none()
+                || current_mapper
+                    .destination
+                    .checked_add(current_mapper.range)
+                    .is_none()
+            {
+                return Err(line.error(range, RANGE_TOO_LONG));
+            }
+
+            current_mapper_group.mappers.push(current_mapper);
+        }
+    }
+
+    almanac.push(current_mapper_group);
+
+    Ok((seeds, almanac))
+}
+
+fn map_one_step(seed: u64, mapper_group: &MapperGroup) -> u64 {
+    let mapper = mapper_group
+        .mappers
+        .iter()
+        .find(|mapper| seed >= mapper.source && seed < mapper.source + mapper.range);
+
+    if let Some(mapper) = mapper {
+        return seed - mapper.source + mapper.destination;
+    }
+
+    seed
+}
+
+fn map_seed_to_location(seed: u64, almanac: &Vec<MapperGroup>) -> u64 {
+    let mut current_seed = seed;
+    // println!("\nNew seed: {}", seed);
+
+    for mapper_group in almanac {
+        let mapped_value = map_one_step(current_seed, mapper_group);
+        // println!(
+        //     "Mapping {} to {} with {}",
+        //     current_seed, mapped_value, mapper_group.name
+        // );
+        current_seed = mapped_value;
+    }
+
+    current_seed
+}
+
+fn get_pairs(seeds: &[u64]) -> Vec<(u64, u64)> {
+    seeds
+        .chunks_exact(2)
+        .map(|pair| (pair[0], pair[1]))
+        .collect()
+}
+
+/// The seeds of part 2: every pair of numbers is the start and length of an
+/// interval of seeds, which parsing keeps within a `u64`.
+fn seed_intervals(seeds: &[u64]) -> Vec<Range<u64>> {
+    get_pairs(seeds)
+        .into_iter()
+        .map(|(start, range)| start..start + range)
+        .collect()
+}
+
+/// Pushes the `seeds` intervals through every group of the almanac, giving
+/// the intervals of locations they end up in.
+fn map_intervals_to_locations(seeds: &[Range<u64>], almanac: &[MapperGroup]) -> Vec<Range<u64>> {
+    almanac
+        .iter()
+        .fold(merge(seeds.to_vec()), |intervals, mapper_group| {
+            let mapped = mapper_group.map_intervals(&intervals);
+
+            debug!(
+                "{}: {} intervals -> {}",
+                mapper_group.name,
+                intervals.len(),
+                mapped.len()
+            );
+
+            mapped
+        })
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = (Vec<u64>, Vec<MapperGroup>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_almanac(input)
+    }
+
+    fn part1((seeds, almanac): &Self::Input<'_>) -> impl Display {
+        seeds
+            .iter()
+            .map(|seed| map_seed_to_location(*seed, almanac))
+            .min()
+            .expect("checked while parsing")
+    }
+
+    fn part2((seeds, almanac): &Self::Input<'_>) -> impl Display {
+        map_intervals_to_locations(&seed_intervals(seeds), almanac)
+            .first()
+            .expect("checked while parsing")
+            .start
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::example_tests!(Day05);
+
+    #[test]
+    fn test_parse_line() {
+        let seeds = parse_seeds(Line::new(1, "seeds: 515785082 87905039 2104518691 503149843 720333403 385234193 1357904101 283386167 93533455 128569683 2844655470 24994629 3934515023 67327818 2655687716 8403417 3120497449 107756881 4055128129 9498708")).unwrap();
+
+        assert_eq!(
+            seeds,
+            vec![
+                515785082, 87905039, 2104518691, 503149843, 720333403, 385234193, 1357904101,
+                283386167, 93533455, 128569683, 2844655470, 24994629, 3934515023, 67327818,
+                2655687716, 8403417, 3120497449, 107756881, 4055128129, 9498708
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_almanac() {
+        let (seeds, almanac) =
+            parse_almanac("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50 48\n\nsoil-to-fertilizer map:\n0 15 37\n").unwrap();
+
+        assert_eq!(seeds, vec![79, 14]);
+        assert_eq!(almanac.len(), 2);
+        assert_eq!(almanac[0].name, "seed-to-soil");
+        assert_eq!(almanac[0].mappers.len(), 2);
+        assert_eq!(almanac[1].mappers.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_almanac_errors() {
+        let error = parse_almanac("").unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (1, "'seeds:'"));
+
+        let error = parse_almanac("seeds: 79 x4").unwrap_err();
+        assert_eq!((error.line, error.span), (1, 10..12));
+
+        let error = parse_almanac("seeds: 79 14\n\na-to-b map:\n50 98").unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (4, "three numbers"));
+
+        let error = parse_almanac("seeds:").unwrap_err();
+        assert_eq!(
+            (error.span, error.expected.as_str()),
+            (6..6, "at least one seed")
+        );
+
+        let error = parse_almanac("seeds: 79 14 55").unwrap_err();
+        assert_eq!(
+            (error.span, error.expected.as_str()),
+            (15..15, "the length of the seed range")
+        );
+
+        let error = parse_almanac("seeds: 18446744073709551615 1").unwrap_err();
+        assert_eq!(
+            (error.span, error.expected.as_str()),
+            (28..29, RANGE_TOO_LONG)
+        );
+
+        let error =
+            parse_almanac("seeds: 79 14\n\na-to-b map:\n18446744073709551610 0 10").unwrap_err();
+        assert_eq!((error.line, error.span), (4, 23..25));
+    }
+
+    #[test]
+    fn test_map_one_step() {
+        let mapper_group = MapperGroup {
+            name: "test".to_string(),
+            mappers: vec![Mapper::new(50, 98, 2), Mapper::new(52, 50, 48)],
+        };
+
+        assert_eq!(map_one_step(98, &mapper_group), 50);
+        assert_eq!(map_one_step(99, &mapper_group), 51);
+        assert_eq!(map_one_step(10, &mapper_group), 10);
+        assert_eq!(map_one_step(79, &mapper_group), 81);
+        assert_eq!(map_one_step(14, &mapper_group), 14);
+        assert_eq!(map_one_step(55, &mapper_group), 57);
+        assert_eq!(map_one_step(13, &mapper_group), 13);
+    }
+
+    #[test]
+    fn test_map_intervals() {
+        let mapper_group = MapperGroup {
+            name: "test".to_string(),
+            mappers: vec![Mapper::new(50, 98, 2), Mapper::new(52, 50, 48)],
+        };
+
+        // 40..60 splits into 40..50, kept, and 50..60, shifted to 52..62.
+        // 90..105 splits into 90..98 to 92..100, 98..100 to 50..52 and
+        // 100..105, kept. Everything then merges back into two intervals.
+        assert_eq!(
+            mapper_group.map_intervals(&[40..60, 90..105]),
+            vec![40..62, 92..105]
+        );
+        assert_eq!(
+            mapper_group.map_intervals(&[98..99, 10..20]),
+            vec![10..20, 50..51]
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        assert_eq!(
+            merge(vec![5..8, 1..3, 3..4, 7..10, 12..12]),
+            vec![1..4, 5..10]
+        );
+        assert_eq!(merge(vec![]), vec![]);
+    }
+
+    #[test]
+    fn test_map_intervals_to_locations() {
+        let input = aoc_core::examples::input(env!("CARGO_MANIFEST_DIR"), "example.txt");
+        let (seeds, almanac) = parse_almanac(&input).unwrap();
+        let locations = map_intervals_to_locations(&seed_intervals(&seeds), &almanac);
+
+        assert_eq!(locations[0].start, 46);
+        // As many locations as seeds, none lost or duplicated on the way.
+        assert_eq!(
+            locations
+                .iter()
+                .map(|interval| interval.end - interval.start)
+                .sum::<u64>(),
+            14 + 13
+        );
+    }
+
+    #[test]
+    fn test_map_seed_to_location() {
+        let almanac = vec![
+            MapperGroup {
+                name: "test".to_string(),
+                mappers: vec![Mapper::new(50, 98, 2), Mapper::new(52, 50, 48)],
+            },
+            MapperGroup {
+                name: "test".to_string(),
+                mappers: vec![
+                    Mapper::new(0, 15, 37),
+                    Mapper::new(37, 52, 2),
+                    Mapper::new(39, 0, 15),
+                ],
+            },
+            MapperGroup {
+                name: "test".to_string(),
+                mappers: vec![
+                    Mapper::new(49, 53, 8),
+                    Mapper::new(0, 11, 42),
+                    Mapper::new(42, 0, 7),
+                    Mapper::new(57, 7, 4),
+                ],
+            },
+            MapperGroup {
+                name: "test".to_string(),
+                mappers: vec![Mapper::new(88, 18, 7), Mapper::new(18, 25, 70)],
+            },
+            MapperGroup {
+                name: "test".to_string(),
+                mappers: vec![
+                    Mapper::new(45, 77, 23),
+                    Mapper::new(81, 45, 19),
+                    Mapper::new(68, 64, 13),
+                ],
+            },
+            MapperGroup {
+                name: "test".to_string(),
+                mappers: vec![Mapper::new(0, 69, 1), Mapper::new(1, 0, 69)],
+            },
+            MapperGroup {
+                name: "test".to_string(),
+                mappers: vec![Mapper::new(60, 56, 37), Mapper::new(56, 93, 4)],
+            },
+        ];
+
+        assert_eq!(map_seed_to_location(79, &almanac), 82);
+        assert_eq!(map_seed_to_location(14, &almanac), 43);
+        assert_eq!(map_seed_to_location(55, &almanac), 86);
+        assert_eq!(map_seed_to_location(13, &almanac), 35);
+    }
+
+    #[test]
+    fn test_get_pairs() {
+        let seeds = vec![
+            515785082, 87905039, 2104518691, 503149843, 720333403, 385234193, 1357904101,
+            283386167, 93533455, 128569683, 2844655470, 24994629, 3934515023, 67327818, 2655687716,
+            8403417, 3120497449, 107756881, 4055128129, 9498708,
+        ];
+
+        let pairs = get_pairs(&seeds);
+
+        assert_eq!(
+            pairs,
+            vec![
+                (515785082, 87905039),
+                (2104518691, 503149843),
+                (720333403, 385234193),
+                (1357904101, 283386167),
+                (93533455, 128569683),
+                (2844655470, 24994629),
+                (3934515023, 67327818),
+                (2655687716, 8403417),
+                (3120497449, 107756881),
+                (4055128129, 9498708),
+            ]
+        );
+    }
+}
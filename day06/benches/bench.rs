@@ -0,0 +1,3 @@
+fn main() {
+    aoc_core::bench::main::<day06::Day06>(env!("CARGO_MANIFEST_DIR"));
+}
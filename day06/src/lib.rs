@@ -0,0 +1,234 @@
+use aoc_core::{error::lines, Line, ParseError, Solution};
+use std::fmt::Display;
+
+#[derive(PartialEq, Debug)]
+pub struct RaceRecord {
+    time: u64,
+    distance: u64,
+}
+
+fn parse_line(line: Line) -> Result<Vec<u64>, ParseError> {
+    let (_, numbers) = line.split_once(line.text, ":")?;
+
+    line.numbers(numbers)
+}
+
+fn parse_kerned_line(line: Line) -> Result<u64, ParseError> {
+    let (_, numbers) = line.split_once(line.text, ":")?;
+
+    numbers
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .parse::<u64>()
+        .map_err(|_| line.error(numbers, "a number"))
+}
+
+fn get_race_lines(input: &str) -> Result<(Line<'_>, Line<'_>), ParseError> {
+    let mut line_iter = lines(input);
+
+    let time = line_iter
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(1, "'Time:'"))?;
+    let distance = line_iter
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(2, "'Distance:'"))?;
+
+    Ok((time, distance))
+}
+
+fn get_races(input: &str) -> Result<Vec<RaceRecord>, ParseError> {
+    let (time_line, distance_line) = get_race_lines(input)?;
+    let time = parse_line(time_line)?;
+    let distance = parse_line(distance_line)?;
+
+    if time.len() != distance.len() {
+        return Err(distance_line.error(
+            distance_line.text,
+            format!("{} distances, one per race", time.len()),
+        ));
+    }
+
+    Ok(time
+        .iter()
+        .zip(distance.iter())
+        .map(|(t, d)| RaceRecord {
+            time: *t,
+            distance: *d,
+        })
+        .collect())
+}
+
+fn get_kerned_race(input: &str) -> Result<RaceRecord, ParseError> {
+    let (time_line, distance_line) = get_race_lines(input)?;
+
+    Ok(RaceRecord {
+        time: parse_kerned_line(time_line)?,
+        distance: parse_kerned_line(distance_line)?,
+    })
+}
+
+fn compute_distance(available_time: u64, press_time: u64) -> u64 {
+    (available_time - press_time) * press_time
+}
+
+fn determine_ways_to_beat_record(record: &RaceRecord) -> u64 {
+    let mut ways = 0;
+    for press_time in 0..record.time {
+        let distance = compute_distance(record.time, press_time);
+        if distance > record.distance {
+            ways += 1;
+        }
+    }
+    ways
+}
+
+fn get_answer_1(race_records: &Vec<RaceRecord>) -> u64 {
+    race_records
+        .iter()
+        .map(|record| determine_ways_to_beat_record(record))
+        .fold(1, |acc, x| acc * x)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = (Vec<RaceRecord>, RaceRecord);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok((get_races(input)?, get_kerned_race(input)?))
+    }
+
+    fn part1((races_records, _): &Self::Input<'_>) -> impl Display {
+        get_answer_1(races_records)
+    }
+
+    fn part2((_, kerned_record): &Self::Input<'_>) -> impl Display {
+        determine_ways_to_beat_record(kerned_record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::example_tests!(Day06);
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(
+            parse_line(Line::new(1, "Time: 1   2 3 4")),
+            Ok(vec![1, 2, 3, 4])
+        );
+        assert_eq!(
+            parse_line(Line::new(1, "Time: 1 2        3 4 5")),
+            Ok(vec![1, 2, 3, 4, 5])
+        );
+        assert_eq!(
+            parse_line(Line::new(2, "Distance:    1    2  3 4 5 6")),
+            Ok(vec![1, 2, 3, 4, 5, 6])
+        );
+    }
+
+    #[test]
+    fn test_get_races() {
+        let input = "Time: 1 2 3 4 5 6\nDistance: 1 2 3 4 5 6";
+
+        assert_eq!(
+            get_races(input).unwrap(),
+            vec![
+                RaceRecord {
+                    time: 1,
+                    distance: 1
+                },
+                RaceRecord {
+                    time: 2,
+                    distance: 2
+                },
+                RaceRecord {
+                    time: 3,
+                    distance: 3
+                },
+                RaceRecord {
+                    time: 4,
+                    distance: 4
+                },
+                RaceRecord {
+                    time: 5,
+                    distance: 5
+                },
+                RaceRecord {
+                    time: 6,
+                    distance: 6
+                },
+            ]
+        )
+    }
+
+    #[test]
+    fn test_get_races_errors() {
+        let error = get_races("Time: 7 15").unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (2, "'Distance:'"));
+
+        let error = get_races("Time: 7 15\nDistance: 9").unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (2, "2 distances, one per race")
+        );
+
+        let error = get_races("Time: 7 1x\nDistance: 9 40").unwrap_err();
+        assert_eq!((error.line, error.span), (1, 8..10));
+    }
+
+    #[test]
+    fn test_get_kerned_race() {
+        let input = "Time:      7  15   30\nDistance:  9  40  200";
+
+        assert_eq!(
+            get_kerned_race(input).unwrap(),
+            RaceRecord {
+                time: 71530,
+                distance: 940200
+            }
+        );
+    }
+
+    #[test]
+    fn test_compute_distance() {
+        assert_eq!(compute_distance(7, 0), 0);
+        assert_eq!(compute_distance(7, 1), 6);
+        assert_eq!(compute_distance(7, 2), 10);
+        assert_eq!(compute_distance(7, 3), 12);
+        assert_eq!(compute_distance(7, 4), 12);
+        assert_eq!(compute_distance(7, 5), 10);
+        assert_eq!(compute_distance(7, 6), 6);
+        assert_eq!(compute_distance(7, 7), 0);
+    }
+
+    #[test]
+    fn test_determine_ways_to_beat_record() {
+        assert_eq!(
+            determine_ways_to_beat_record(&RaceRecord {
+                time: 7,
+                distance: 9
+            }),
+            4
+        );
+        assert_eq!(
+            determine_ways_to_beat_record(&RaceRecord {
+                time: 15,
+                distance: 40
+            }),
+            8
+        );
+        assert_eq!(
+            determine_ways_to_beat_record(&RaceRecord {
+                time: 30,
+                distance: 200
+            }),
+            9
+        );
+    }
+}
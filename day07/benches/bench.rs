@@ -0,0 +1,3 @@
+fn main() {
+    aoc_core::bench::main::<day07::Day07>(env!("CARGO_MANIFEST_DIR"));
+}
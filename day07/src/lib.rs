@@ -0,0 +1,370 @@
+use std::cmp::Ordering;
+use std::{collections::HashMap, fmt::Display};
+
+use aoc_core::{error::lines, Line, ParseError, Solution};
+
+#[derive(PartialEq, Debug, Eq, Clone)]
+pub enum HandType {
+    FiveOfAKind(String),
+    FourOfAKind(String),
+    FullHouse(String),
+    ThreeOfAKind(String),
+    TwoPair(String),
+    OnePair(String),
+    HighCard(String),
+}
+
+impl HandType {
+    fn get_value(&self) -> u8 {
+        match self {
+            HandType::FiveOfAKind(_) => 7,
+            HandType::FourOfAKind(_) => 6,
+            HandType::FullHouse(_) => 5,
+            HandType::ThreeOfAKind(_) => 4,
+            HandType::TwoPair(_) => 3,
+            HandType::OnePair(_) => 2,
+            HandType::HighCard(_) => 1,
+        }
+    }
+}
+
+fn extract_hand_from_enum(hand_type: &HandType) -> &str {
+    match hand_type {
+        HandType::FiveOfAKind(hand) => hand,
+        HandType::FourOfAKind(hand) => hand,
+        HandType::FullHouse(hand) => hand,
+        HandType::ThreeOfAKind(hand) => hand,
+        HandType::TwoPair(hand) => hand,
+        HandType::OnePair(hand) => hand,
+        HandType::HighCard(hand) => hand,
+    }
+}
+
+/// The strength of `card`, a joker being weaker than any other card when
+/// `jokers` holds (part 2) and a jack between the ten and the queen otherwise.
+fn card_value(card: char, jokers: bool) -> u32 {
+    match card {
+        'A' => 14,
+        'K' => 13,
+        'Q' => 12,
+        'J' if jokers => 1,
+        'J' => 11,
+        'T' => 10,
+        _ => card.to_digit(10).unwrap(),
+    }
+}
+
+fn compare_card(card1: char, card2: char, jokers: bool) -> Ordering {
+    card_value(card1, jokers).cmp(&card_value(card2, jokers))
+}
+
+/// Orders hands by type, then card by card with the given meaning of 'J'.
+fn compare_hands(hand1: &HandType, hand2: &HandType, jokers: bool) -> Ordering {
+    let self_value = hand1.get_value();
+    let other_value = hand2.get_value();
+
+    if self_value != other_value {
+        return self_value.cmp(&other_value);
+    }
+
+    let self_hand = extract_hand_from_enum(hand1);
+    let other_hand = extract_hand_from_enum(hand2);
+
+    for (a, b) in self_hand.chars().zip(other_hand.chars()) {
+        let card_cmp = compare_card(a, b, jokers);
+
+        if card_cmp != Ordering::Equal {
+            return card_cmp;
+        }
+    }
+
+    Ordering::Equal
+}
+
+impl Ord for HandType {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_hands(self, other, false)
+    }
+}
+
+impl PartialOrd for HandType {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+const CARDS: &str = "AKQJT98765432";
+
+fn parse_hand_and_bid(line: Line<'_>) -> Result<(&str, u32), ParseError> {
+    let (hand, bid) = line.split_once(line.text, " ")?;
+
+    if let Some((i, card)) = hand.char_indices().find(|(_, c)| !CARDS.contains(*c)) {
+        return Err(line.error(
+            &hand[i..i + card.len_utf8()],
+            format!("a card among {}", CARDS),
+        ));
+    }
+
+    if hand.len() != 5 {
+        return Err(line.error(hand, "a hand of five cards"));
+    }
+
+    Ok((hand, line.number(bid)?))
+}
+
+fn parse_line(line: Line) -> Result<(HandType, u32), ParseError> {
+    let (hand, bid) = parse_hand_and_bid(line)?;
+
+    Ok((get_hand_type(hand), bid))
+}
+
+fn parse_line_2(line: Line) -> Result<(HandType, u32), ParseError> {
+    let (hand, bid) = parse_hand_and_bid(line)?;
+
+    Ok((get_hand_type_2(hand), bid))
+}
+
+fn get_hand_type(hand: &str) -> HandType {
+    let mut cards_count: HashMap<char, u32> = HashMap::new();
+
+    for card in hand.chars() {
+        let count = cards_count.entry(card).or_insert(0);
+        *count += 1;
+    }
+
+    let mut counts = cards_count.values().collect::<Vec<&u32>>();
+
+    counts.sort_by(|a, b| b.cmp(a));
+
+    if *counts[0] == 5 {
+        return HandType::FiveOfAKind(String::from(hand));
+    }
+
+    if *counts[0] == 4 {
+        return HandType::FourOfAKind(String::from(hand));
+    }
+
+    if counts.len() == 2 && *counts[0] == 3 {
+        return HandType::FullHouse(String::from(hand));
+    }
+
+    if counts.len() == 3 && *counts[0] == 3 {
+        return HandType::ThreeOfAKind(String::from(hand));
+    }
+
+    if counts.len() == 3 && *counts[0] == 2 && *counts[1] == 2 {
+        return HandType::TwoPair(String::from(hand));
+    }
+
+    if counts.len() == 4 && *counts[0] == 2 {
+        return HandType::OnePair(String::from(hand));
+    }
+
+    HandType::HighCard(String::from(hand))
+}
+
+fn get_hand_type_2(hand: &str) -> HandType {
+    let mut cards_count: HashMap<char, u32> = HashMap::new();
+
+    let mut max_card = '0';
+    let mut max_card_count = 0;
+
+    for card in hand.chars() {
+        let count: &mut u32 = cards_count.entry(card).or_insert(0);
+        *count += 1;
+
+        if card != 'J' && *count > max_card_count {
+            max_card_count = *count;
+            max_card = card;
+        }
+    }
+
+    if let Some(joker_count) = cards_count.get(&'J') {
+        let joker_count = *joker_count;
+        let count = cards_count.entry(max_card).or_insert(0);
+        *count += joker_count;
+
+        if cards_count.len() > 1 {
+            cards_count.remove(&'J');
+        }
+    }
+
+    let mut counts = cards_count.values().collect::<Vec<&u32>>();
+
+    counts.sort_by(|a, b| b.cmp(a));
+
+    if *counts[0] == 5 {
+        return HandType::FiveOfAKind(String::from(hand));
+    }
+
+    if *counts[0] == 4 {
+        return HandType::FourOfAKind(String::from(hand));
+    }
+
+    if counts.len() == 2 && *counts[0] == 3 {
+        return HandType::FullHouse(String::from(hand));
+    }
+
+    if counts.len() == 3 && *counts[0] == 3 {
+        return HandType::ThreeOfAKind(String::from(hand));
+    }
+
+    if counts.len() == 3 && *counts[0] == 2 && *counts[1] == 2 {
+        return HandType::TwoPair(String::from(hand));
+    }
+
+    if counts.len() == 4 && *counts[0] == 2 {
+        return HandType::OnePair(String::from(hand));
+    }
+
+    HandType::HighCard(String::from(hand))
+}
+
+fn rank_hands(input: &[(HandType, u32)], jokers: bool) -> u32 {
+    let mut hands: Vec<(HandType, u32)> = input.to_vec();
+    hands.sort_by(|a, b| compare_hands(&a.0, &b.0, jokers));
+
+    let mut sum = 0;
+
+    for (rank, hand) in hands.iter().enumerate() {
+        sum += hand.1 * (rank as u32 + 1);
+    }
+
+    sum
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = (Vec<(HandType, u32)>, Vec<(HandType, u32)>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok((
+            lines(input).map(parse_line).collect::<Result<_, _>>()?,
+            lines(input).map(parse_line_2).collect::<Result<_, _>>()?,
+        ))
+    }
+
+    fn part1((parsed, _): &Self::Input<'_>) -> impl Display {
+        rank_hands(parsed, false)
+    }
+
+    fn part2((_, parsed): &Self::Input<'_>) -> impl Display {
+        rank_hands(parsed, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::get_hand_type_2;
+
+    use super::*;
+
+    aoc_core::example_tests!(Day07);
+
+    #[test]
+    fn test_parse_line() {
+        let line = "32T3K 765";
+        let (hand, bid) = parse_line(Line::new(1, line)).unwrap();
+
+        assert_eq!(hand, HandType::OnePair(String::from("32T3K")));
+        assert_eq!(bid, 765);
+    }
+
+    #[test]
+    fn test_parse_line_errors() {
+        let error = parse_line(Line::new(4, "32X3K 765")).unwrap_err();
+        assert_eq!((error.line, error.span), (4, 2..3));
+
+        let error = parse_line(Line::new(1, "32T3 765")).unwrap_err();
+        assert_eq!(
+            (error.span, error.expected.as_str()),
+            (0..4, "a hand of five cards")
+        );
+
+        let error = parse_line(Line::new(1, "32T3K")).unwrap_err();
+        assert_eq!(error.expected, "' '");
+
+        let error = parse_line_2(Line::new(1, "32T3K -1")).unwrap_err();
+        assert_eq!(error.span, 6..8);
+    }
+
+    #[test]
+    fn test_get_hand_type() {
+        assert_eq!(
+            get_hand_type("32T3K"),
+            HandType::OnePair(String::from("32T3K"))
+        );
+        assert_eq!(
+            get_hand_type("T55J5"),
+            HandType::ThreeOfAKind(String::from("T55J5"))
+        );
+        assert_eq!(
+            get_hand_type("KK677"),
+            HandType::TwoPair(String::from("KK677"))
+        );
+        assert_eq!(
+            get_hand_type("KTJJT"),
+            HandType::TwoPair(String::from("KTJJT"))
+        );
+        assert_eq!(
+            get_hand_type("QQQJA"),
+            HandType::ThreeOfAKind(String::from("QQQJA"))
+        );
+        assert_eq!(
+            get_hand_type("AAAAA"),
+            HandType::FiveOfAKind(String::from("AAAAA"))
+        );
+        assert_eq!(
+            get_hand_type("AAAAB"),
+            HandType::FourOfAKind(String::from("AAAAB"))
+        );
+        assert_eq!(
+            get_hand_type("AAABB"),
+            HandType::FullHouse(String::from("AAABB"))
+        );
+        assert_eq!(
+            get_hand_type("ABCDE"),
+            HandType::HighCard(String::from("ABCDE"))
+        );
+    }
+
+    #[test]
+    fn test_handtype_cmp() {
+        let five_of_a_kind: HandType = HandType::FiveOfAKind(String::from("AAAAA"));
+        let four_of_a_kind = HandType::FourOfAKind(String::from("AAAAT"));
+
+        assert_eq!(five_of_a_kind.cmp(&four_of_a_kind), Ordering::Greater);
+        assert_eq!(four_of_a_kind.cmp(&five_of_a_kind), Ordering::Less);
+        assert_eq!(four_of_a_kind.cmp(&four_of_a_kind), Ordering::Equal);
+
+        let full_house_1: HandType = HandType::FullHouse(String::from("TTAAA"));
+        let full_house_2: HandType = HandType::FullHouse(String::from("AAATT"));
+
+        assert_eq!(full_house_1.cmp(&full_house_2), Ordering::Less);
+
+        let jack = HandType::OnePair(String::from("JJ234"));
+        let ten = HandType::OnePair(String::from("TT234"));
+
+        assert_eq!(jack.cmp(&ten), Ordering::Greater);
+        assert_eq!(compare_hands(&jack, &ten, true), Ordering::Less);
+    }
+
+    #[test]
+    fn test_get_hand_type_2() {
+        assert_eq!(
+            get_hand_type_2("T55J5"),
+            HandType::FourOfAKind(String::from("T55J5"))
+        );
+        assert_eq!(
+            get_hand_type_2("KTJJT"),
+            HandType::FourOfAKind(String::from("KTJJT"))
+        );
+        assert_eq!(
+            get_hand_type_2("QQQJA"),
+            HandType::FourOfAKind(String::from("QQQJA"))
+        );
+    }
+}
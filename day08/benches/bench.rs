@@ -0,0 +1,3 @@
+fn main() {
+    aoc_core::bench::main::<day08::Day08>(env!("CARGO_MANIFEST_DIR"));
+}
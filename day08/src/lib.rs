@@ -0,0 +1,325 @@
+use aoc_core::{error::lines, Line, ParseError, Solution};
+use std::{collections::HashMap, fmt::Display};
+
+pub type Graph<'a> = HashMap<String, Vec<&'a str>>;
+
+fn parse_node_line(line: Line<'_>) -> Result<(&str, Vec<&str>), ParseError> {
+    let (node, children) = line.split_once(line.text, " = ")?;
+
+    let children = line.strip_suffix(line.strip_prefix(children, "(")?, ")")?;
+    let (left, right) = line.split_once(children, ", ")?;
+
+    Ok((node, vec![left, right]))
+}
+
+fn parse_input(input: &str) -> Result<(&str, Graph<'_>), ParseError> {
+    let mut graph: Graph = HashMap::new();
+
+    let mut line_iter = lines(input);
+
+    let directions = line_iter
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(1, "a list of directions"))?;
+
+    if let Some((i, direction)) = directions
+        .text
+        .char_indices()
+        .find(|(_, c)| *c != 'L' && *c != 'R')
+    {
+        return Err(directions.error(&directions.text[i..i + direction.len_utf8()], "'L' or 'R'"));
+    }
+
+    line_iter.next();
+
+    let nodes = line_iter
+        .map(|line| Ok((line, parse_node_line(line)?)))
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    for (_, (node, children)) in &nodes {
+        graph.insert(String::from(*node), children.clone());
+    }
+
+    // Every child must be defined on its own line, or walking to it fails.
+    for (line, (_, children)) in &nodes {
+        if let Some(child) = children.iter().find(|child| !graph.contains_key(**child)) {
+            return Err(line.error(child, "a node defined in the network"));
+        }
+    }
+
+    Ok((directions.text, graph))
+}
+
+fn traverse_graph(graph: &Graph, directions: &str) -> i32 {
+    let mut current_node = "AAA";
+
+    let mut steps = 0;
+
+    for direction in directions.chars().cycle() {
+        // println!(
+        //     "Current node: {} -> {}{:?}",
+        //     current_node, direction, current_children
+        // );
+        steps += 1;
+        let children = graph.get(current_node).unwrap();
+
+        match direction {
+            'L' => {
+                current_node = children[0];
+            }
+            'R' => {
+                current_node = children[1];
+            }
+            _ => panic!("Invalid direction: {}", direction),
+        }
+
+        if current_node == "ZZZ" {
+            break;
+        }
+    }
+
+    steps
+}
+
+fn traverse_graph_2(graph: &Graph, directions: &str, start_node: &str) -> i32 {
+    let mut current_node = start_node;
+
+    let mut steps = 0;
+
+    for direction in directions.chars().cycle() {
+        // println!(
+        //     "Current node: {} -> {}{:?}",
+        //     current_node, direction, current_children
+        // );
+        steps += 1;
+        let children = graph.get(current_node).unwrap();
+
+        match direction {
+            'L' => {
+                current_node = children[0];
+            }
+            'R' => {
+                current_node = children[1];
+            }
+            _ => panic!("Invalid direction: {}", direction),
+        }
+
+        if current_node.ends_with("Z") {
+            break;
+        }
+    }
+
+    steps
+}
+
+// fn traverse_graph_multiple(graph: &Graph, directions: &str) -> i32 {
+//     let starting_nodes = find_starting_nodes(graph);
+
+//     let mut current_nodes = starting_nodes;
+
+//     let mut steps = 0;
+
+//     for direction in directions.chars().cycle() {
+//         // println!("Current nodes: {:?} -> {}", current_nodes, direction);
+//         steps += 1;
+//         let mut next_nodes: Vec<String> = Vec::new();
+
+//         for node in current_nodes {
+//             let children = graph.get(&node).unwrap();
+
+//             match direction {
+//                 'L' => {
+//                     next_nodes.push(children[0].to_string());
+//                 }
+//                 'R' => {
+//                     next_nodes.push(children[1].to_string());
+//                 }
+//                 _ => panic!("Invalid direction: {}", direction),
+//             }
+//         }
+
+//         current_nodes = next_nodes;
+
+//         if are_all_nodes_final(&current_nodes) {
+//             println!("Found final nodes: {:?}", current_nodes);
+//             break;
+//         }
+//     }
+
+//     steps
+// }
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    a * b / gcd(a, b)
+}
+
+fn lcm_vec(numbers: Vec<u64>) -> u64 {
+    numbers.into_iter().fold(1, |a, b| lcm(a, b))
+}
+
+fn traverse_graph_multiple(graph: &Graph, directions: &str) -> u64 {
+    let starting_nodes = find_starting_nodes(graph);
+
+    let all_steps: Vec<u64> = starting_nodes
+        .iter()
+        .map(|node| traverse_graph_2(graph, directions, node) as u64)
+        .collect();
+
+    let steps = lcm_vec(all_steps);
+
+    steps
+}
+
+fn find_starting_nodes(graph: &Graph) -> Vec<String> {
+    let mut starting_nodes: Vec<String> = Vec::new();
+
+    for (node, _) in graph {
+        if node.ends_with("A") {
+            starting_nodes.push(node.to_string());
+        }
+    }
+
+    // The graph is a HashMap: sort so the order doesn't change between runs.
+    starting_nodes.sort();
+
+    starting_nodes
+}
+
+fn are_all_nodes_final(nodes: &Vec<String>) -> bool {
+    for node in nodes {
+        if !node.ends_with("Z") {
+            return false;
+        }
+    }
+
+    true
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = (&'a str, Graph<'a>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((directions, graph): &Self::Input<'_>) -> impl Display {
+        traverse_graph(graph, directions)
+    }
+
+    fn part2((directions, graph): &Self::Input<'_>) -> impl Display {
+        traverse_graph_multiple(graph, directions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::examples;
+
+    aoc_core::example_tests!(Day08);
+
+    fn example(name: &str) -> String {
+        examples::input(env!("CARGO_MANIFEST_DIR"), name)
+    }
+
+    #[test]
+    fn test_parse_node_line() {
+        let line = "FCG = (PLG, GXC)";
+        let (node, children) = parse_node_line(Line::new(1, line)).unwrap();
+
+        assert_eq!(node, "FCG");
+        assert_eq!(children, vec!["PLG", "GXC"]);
+    }
+
+    #[test]
+    fn test_parse_node_line_errors() {
+        let error = parse_node_line(Line::new(3, "FCG = (PLG, GXC")).unwrap_err();
+        assert_eq!((error.line, error.span), (3, 15..15));
+
+        let error = parse_node_line(Line::new(1, "FCG = PLG, GXC)")).unwrap_err();
+        assert_eq!(error.expected, "'('");
+
+        let error = parse_node_line(Line::new(1, "FCG (PLG, GXC)")).unwrap_err();
+        assert_eq!(error.expected, "'='");
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let error = parse_input("LRX\n\nAAA = (BBB, CCC)").unwrap_err();
+        assert_eq!((error.line, error.span), (1, 2..3));
+
+        let error = parse_input("LR\n\nAAA = (BBB CCC)").unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (3, "','"));
+
+        let error = parse_input("LR\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZY)").unwrap_err();
+        assert_eq!(
+            (error.line, error.span, error.expected.as_str()),
+            (4, 12..15, "a node defined in the network")
+        );
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = example("example1.txt");
+        let (directions, graph) = parse_input(&input).unwrap();
+
+        assert_eq!(directions, "RL");
+        assert_eq!(graph.len(), 7);
+        assert_eq!(*graph.get("AAA").unwrap(), vec!["BBB", "CCC"]);
+        assert_eq!(*graph.get("BBB").unwrap(), vec!["DDD", "EEE"]);
+        assert_eq!(*graph.get("CCC").unwrap(), vec!["ZZZ", "GGG"]);
+    }
+
+    #[test]
+    fn test_traverse_graph() {
+        let input = example("example1.txt");
+        let (directions, graph) = parse_input(&input).unwrap();
+
+        let steps = traverse_graph(&graph, &directions);
+
+        assert_eq!(steps, 2)
+    }
+
+    #[test]
+    fn test_traverse_graph_with_cycles() {
+        let input = example("example2.txt");
+        let (directions, graph) = parse_input(&input).unwrap();
+
+        let steps = traverse_graph(&graph, &directions);
+
+        assert_eq!(steps, 6)
+    }
+
+    #[test]
+    fn test_find_starting_nodes() {
+        let input = example("example3.txt");
+
+        let (_, graph) = parse_input(&input).unwrap();
+
+        let starting_nodes = find_starting_nodes(&graph);
+
+        assert_eq!(starting_nodes, vec!["11A", "22A"]);
+    }
+
+    #[test]
+    fn test_traverse_graph_multiple() {
+        let input = example("example3.txt");
+
+        let (directions, graph) = parse_input(&input).unwrap();
+
+        let steps = traverse_graph_multiple(&graph, &directions);
+
+        assert_eq!(steps, 6);
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_core::bench::main::<day09::Day09>(env!("CARGO_MANIFEST_DIR"));
+}
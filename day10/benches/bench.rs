@@ -0,0 +1,3 @@
+fn main() {
+    aoc_core::bench::main::<day10::Day10>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,331 @@
+use aoc_core::{error::lines, trace, Cell, Grid, ParseError, Solution};
+use std::{cmp::max, collections::HashSet, fmt::Display};
+
+pub type Map = Grid<char>;
+
+const TILES: &str = "|-LJ7F.S";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+fn get_allowed_directions(character: char) -> Vec<Direction> {
+    let direction = match character {
+        '|' => vec![Direction::North, Direction::South],
+        '-' => vec![Direction::West, Direction::East],
+        'L' => vec![Direction::North, Direction::East],
+        'J' => vec![Direction::North, Direction::West],
+        '7' => vec![Direction::South, Direction::West],
+        'F' => vec![Direction::South, Direction::East],
+        '.' => vec![
+            Direction::North,
+            Direction::South,
+            Direction::East,
+            Direction::West,
+        ],
+        'S' => vec![
+            Direction::North,
+            Direction::South,
+            Direction::East,
+            Direction::West,
+        ],
+        _ => panic!("Invalid direction"),
+    };
+    direction
+}
+
+fn allowed_directions_to_char(direction: &HashSet<Direction>) -> char {
+    match direction {
+        direction if *direction == HashSet::from([Direction::North, Direction::South]) => '|',
+        direction if *direction == HashSet::from([Direction::West, Direction::East]) => '-',
+        direction if *direction == HashSet::from([Direction::North, Direction::East]) => 'L',
+        direction if *direction == HashSet::from([Direction::North, Direction::West]) => 'J',
+        direction if *direction == HashSet::from([Direction::South, Direction::West]) => '7',
+        direction if *direction == HashSet::from([Direction::South, Direction::East]) => 'F',
+        _ => panic!("Invalid direction"),
+    }
+}
+
+fn add_direction((row, col): Cell, direction: Direction) -> Cell {
+    match direction {
+        Direction::North => (row - 1, col),
+        Direction::South => (row + 1, col),
+        Direction::East => (row, col + 1),
+        Direction::West => (row, col - 1),
+    }
+}
+
+fn is_bounded(map: &Map, cell: Cell) -> bool {
+    map.get(cell).is_some_and(|tile| *tile != '.')
+}
+
+fn is_only_bounded(map: &Map, cell: Cell) -> bool {
+    map.get(cell).is_some_and(|tile| *tile == '.')
+}
+
+fn is_cell_border(map: &Map, (row, col): Cell) -> bool {
+    row == 0 || row == map.height() as i32 - 1 || col == 0 || col == map.width() as i32 - 1
+}
+
+fn can_go(map: &Map, from: Cell, to: Cell) -> bool {
+    if (from.0 - to.0).abs() != 1 && (from.1 - to.1).abs() != 1 || !is_bounded(map, to) {
+        return false;
+    }
+
+    let to_cell = map[to];
+
+    let allowed_directions = get_allowed_directions(to_cell);
+
+    for direction in allowed_directions {
+        let (row, col) = add_direction(to, direction);
+
+        if (row, col) == from {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn dfs(map: &Map, visited: &mut Grid<bool>, (row, col): Cell) -> i32 {
+    let current_cell = (row, col);
+
+    if !is_bounded(map, (row, col)) || visited[(row, col)] {
+        return 0;
+    }
+
+    visited[(row, col)] = true;
+
+    let allowed_directions: Vec<Direction> = get_allowed_directions(map[(row, col)]);
+
+    let mut steps = 0;
+
+    for direction in allowed_directions {
+        let next_cell: (i32, i32) = add_direction((row, col), direction);
+
+        if can_go(map, current_cell, next_cell) {
+            steps = max(steps, dfs(map, visited, next_cell));
+        }
+    }
+
+    steps + 1
+}
+
+fn find_starting_cell(map: &Map) -> Cell {
+    map.position(|tile| *tile == 'S')
+        .expect("No starting cell found")
+}
+
+fn get_farthest_distance(map: &Map) -> (i32, Grid<bool>) {
+    let mut visited = Grid::filled(map.width(), map.height(), false);
+
+    let starting_cell = find_starting_cell(map);
+
+    (dfs(map, &mut visited, starting_cell) / 2, visited)
+}
+
+fn substitute_starting_point(map: &mut Map) {
+    let starting_cell = find_starting_cell(map);
+    let all_directions: Vec<Direction> = vec![
+        Direction::North,
+        Direction::South,
+        Direction::East,
+        Direction::West,
+    ];
+
+    let allowed_directions: HashSet<Direction> = all_directions
+        .iter()
+        .filter(|direction| {
+            can_go(
+                map,
+                starting_cell,
+                add_direction(starting_cell, **direction),
+            )
+        })
+        .cloned()
+        .collect::<HashSet<Direction>>();
+
+    let new_char: char = allowed_directions_to_char(&allowed_directions);
+
+    map[starting_cell] = 'L';
+}
+
+fn dfs_enclosed(map: &Map, visited: &mut Grid<bool>, (row, col): Cell) -> (i32, bool) {
+    let current_cell = (row, col);
+
+    if !is_only_bounded(map, (row, col)) || visited[(row, col)] || map[(row, col)] != '.' {
+        return (0, false);
+    }
+
+    visited[(row, col)] = true;
+
+    let allowed_directions: Vec<Direction> = get_allowed_directions(map[(row, col)]);
+
+    let mut steps = 0;
+    let mut is_current_cell_border: bool = is_cell_border(map, current_cell);
+
+    for direction in allowed_directions {
+        let next_cell: (i32, i32) = add_direction(current_cell, direction);
+        let (added_steps, is_border) = dfs_enclosed(map, visited, next_cell);
+        steps += added_steps;
+        is_current_cell_border = is_current_cell_border || is_border;
+    }
+    trace!(
+        "({},{}) steps: {}, is_current_cell_border={}",
+        row,
+        col,
+        steps + 1,
+        is_current_cell_border
+    );
+
+    (steps + 1, is_current_cell_border)
+}
+
+fn find_enclosed_tiles(map: &Map, loop_cells: &mut Grid<bool>) -> i32 {
+    let mut enclosed_tiles = 0;
+    for row in 0..map.height() as i32 {
+        let mut is_inside: bool = false;
+
+        for col in 0..map.width() as i32 {
+            let cell = (row, col);
+            let cell_char = map[(row, col)];
+
+            if loop_cells[(row, col)] {
+                if cell_char == 'L' {
+                    is_inside = !is_inside
+                } else if cell_char == '|' {
+                    is_inside = !is_inside;
+                } else if cell_char == 'J' {
+                    is_inside = !is_inside;
+                } else if cell_char == 'L' {
+                    is_inside = !is_inside;
+                }
+            }
+
+            if is_inside && cell_char == '.' {
+                enclosed_tiles += 1;
+            }
+        }
+    }
+
+    enclosed_tiles
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input<'a> = Map;
+
+    /// Only pipes, ground and a single starting tile are allowed.
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let map = Map::try_parse_with(
+            input,
+            |c| TILES.contains(c).then_some(c),
+            &format!("a tile among {}", TILES),
+        )?;
+
+        let mut starts = lines(input)
+            .flat_map(|line| line.text.match_indices('S').map(move |(i, s)| (line, i, s)));
+
+        if starts.next().is_none() {
+            return Err(ParseError::end_of_input(
+                map.height() + 1,
+                "a starting tile 'S'",
+            ));
+        }
+
+        if let Some((line, i, s)) = starts.next() {
+            return Err(line.error(&line.text[i..i + s.len()], "a single starting tile 'S'"));
+        }
+
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Input<'_>) -> impl Display {
+        get_farthest_distance(map).0
+    }
+
+    fn part2(map: &Self::Input<'_>) -> impl Display {
+        let (_, mut visited) = get_farthest_distance(map);
+
+        find_enclosed_tiles(map, &mut visited)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::examples;
+
+    aoc_core::example_tests!(Day10);
+
+    fn example(name: &str) -> Map {
+        Day10::parse(&examples::input(env!("CARGO_MANIFEST_DIR"), name)).unwrap()
+    }
+
+    #[test]
+    fn test_is_connected() {
+        let map: Map = Map::parse(".|..\n|SF.\n.J..\n....");
+
+        assert_eq!(can_go(&map, (1, 1), (1, 2)), false);
+        assert_eq!(can_go(&map, (1, 1), (2, 1)), true);
+        assert_eq!(can_go(&map, (1, 1), (0, 1)), true);
+        assert_eq!(can_go(&map, (1, 1), (1, 0)), false);
+        assert_eq!(can_go(&map, (1, 1), (0, 0)), false);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Day10::parse("S-7\n|.X").unwrap_err();
+        assert_eq!((error.line, error.span), (2, 2..3));
+        assert_eq!(error.expected, "a tile among |-LJ7F.S");
+
+        let error = Day10::parse("S-7\n|.").unwrap_err();
+        assert_eq!(error.expected, "a row of 3 cells");
+
+        let error = Day10::parse("F-7\nL-J").unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (3, "a starting tile 'S'")
+        );
+
+        let error = Day10::parse("S-7\n|.S").unwrap_err();
+        assert_eq!((error.line, error.span), (2, 2..3));
+    }
+
+    #[test]
+    fn test_find_starting_cell() {
+        let map: Map = Map::parse(".|..\n|SF.\n.J..\n....");
+
+        assert_eq!(find_starting_cell(&map), (1, 1));
+    }
+
+    #[test]
+    fn test_get_farthest_distance_1() {
+        let map = example("example2.txt");
+
+        assert_eq!(get_farthest_distance(&map).0, 8);
+    }
+
+    #[test]
+    fn test_get_farthest_distance_2() {
+        let map = example("example1.txt");
+
+        assert_eq!(get_farthest_distance(&map).0, 4);
+    }
+
+    #[test]
+    fn test_find_enclosed_tiles() {
+        let map = example("example3.txt");
+
+        let (_, mut visited) = get_farthest_distance(&map);
+
+        assert_eq!(find_enclosed_tiles(&map, &mut visited), 4);
+    }
+}
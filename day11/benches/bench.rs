@@ -0,0 +1,3 @@
+fn main() {
+    aoc_core::bench::main::<day11::Day11>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_core::bench::main::<day12::Day12>(env!("CARGO_MANIFEST_DIR"));
+}